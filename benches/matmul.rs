@@ -1,6 +1,6 @@
 use rand::Rng;
 
-use numerics::core::gemm::gemm;
+use numerics::core::gemm::{gemm, gemm_strassen};
 use numerics::core::matrix::Matrix;
 
 fn main() {
@@ -10,9 +10,9 @@ fn main() {
 
 #[divan::bench(args = [128, 512, 1024])]
 fn transpose_bench(bencher: divan::Bencher, n: usize) {
-    let gen: fn(usize, usize) -> f32 = |_, _| rand::thread_rng().gen();
+    let mut rng = rand::thread_rng();
 
-    let a = Matrix::from_gen(n, n, gen);
+    let a: Matrix<f32> = Matrix::from_fn(n, n, |_, _| rng.gen());
 
     bencher.bench(|| {
         a.transpose();
@@ -30,3 +30,27 @@ fn matmul_bench(bencher: divan::Bencher, n: usize) {
         gemm(&a, &b);
     })
 }
+
+#[divan::bench(sample_count=20, args = [128, 512, 1024])]
+fn matmul_rectangular_bench(bencher: divan::Bencher, n: usize) {
+    let gen: fn(usize, usize) -> f64 = |i, j| ((i + j) as f64) / 2048.0;
+
+    let a = Matrix::from_gen(2 * n, n / 2, gen);
+    let b = Matrix::from_gen(n / 2, 3 * n, gen);
+
+    bencher.bench(|| {
+        gemm(&a, &b);
+    })
+}
+
+#[divan::bench(sample_count=20, args = [512, 1024, 2048])]
+fn strassen_bench(bencher: divan::Bencher, n: usize) {
+    let gen: fn(usize, usize) -> f32 = |i, j| ((i + j) as f32) / 2048.0;
+
+    let a = Matrix::from_gen(n, n, gen);
+    let b = Matrix::from_gen(n, n, gen);
+
+    bencher.bench(|| {
+        gemm_strassen(&a, &b, 256);
+    })
+}
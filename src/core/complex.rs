@@ -0,0 +1,302 @@
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::core::scalar::{One, RealField, Zero};
+
+/// A complex number `re + i * im`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Complex<T> {
+    pub re: T,
+    pub im: T,
+}
+
+impl<T> Complex<T> {
+    pub fn new(re: T, im: T) -> Self {
+        Self { re, im }
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Copy + Neg<Output = T>,
+{
+    pub fn conj(&self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl<T> Complex<T>
+where
+    T: Copy + Add<Output = T> + Mul<Output = T>,
+{
+    /// Squared modulus `re^2 + im^2`.
+    pub fn norm_sqr(&self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+macro_rules! complex_float {
+    ($type:ty) => {
+        impl Complex<$type> {
+            /// Modulus, computed without undue overflow or underflow.
+            pub fn abs(&self) -> $type {
+                self.re.hypot(self.im)
+            }
+
+            /// Argument in `(-pi, pi]`.
+            pub fn arg(&self) -> $type {
+                self.im.atan2(self.re)
+            }
+        }
+
+        impl From<$type> for Complex<$type> {
+            fn from(re: $type) -> Self {
+                Self { re, im: 0.0 }
+            }
+        }
+    };
+}
+
+complex_float!(f32);
+complex_float!(f64);
+
+impl<T> Add for Complex<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self {
+            re: self.re + other.re,
+            im: self.im + other.im,
+        }
+    }
+}
+
+impl<T> Sub for Complex<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self {
+            re: self.re - other.re,
+            im: self.im - other.im,
+        }
+    }
+}
+
+impl<T> Mul for Complex<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        Self {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+}
+
+impl<T> Div for Complex<T>
+where
+    T: RealField,
+{
+    type Output = Self;
+
+    /// Smith's algorithm, which divides through by the larger component of `other` instead of
+    /// by its squared modulus, so that it does not overflow or underflow for large or tiny
+    /// operands.
+    fn div(self, other: Self) -> Self::Output {
+        if other.re.abs() >= other.im.abs() {
+            let ratio = other.im / other.re;
+            let denom = other.re + other.im * ratio;
+            Self {
+                re: (self.re + self.im * ratio) / denom,
+                im: (self.im - self.re * ratio) / denom,
+            }
+        } else {
+            let ratio = other.re / other.im;
+            let denom = other.re * ratio + other.im;
+            Self {
+                re: (self.re * ratio + self.im) / denom,
+                im: (self.im * ratio - self.re) / denom,
+            }
+        }
+    }
+}
+
+impl<T> Neg for Complex<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
+
+impl<T> AddAssign for Complex<T>
+where
+    T: AddAssign,
+{
+    fn add_assign(&mut self, other: Self) {
+        self.re += other.re;
+        self.im += other.im;
+    }
+}
+
+impl<T> SubAssign for Complex<T>
+where
+    T: SubAssign,
+{
+    fn sub_assign(&mut self, other: Self) {
+        self.re -= other.re;
+        self.im -= other.im;
+    }
+}
+
+impl<T> MulAssign for Complex<T>
+where
+    T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>,
+{
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<T> DivAssign for Complex<T>
+where
+    T: RealField,
+{
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl<T> Sum for Complex<T>
+where
+    T: Zero + Add<Output = T>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, z| acc + z)
+    }
+}
+
+impl<T> Zero for Complex<T>
+where
+    T: Zero,
+{
+    fn zero() -> Self {
+        Self {
+            re: T::zero(),
+            im: T::zero(),
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T> One for Complex<T>
+where
+    T: Zero + One,
+{
+    fn one() -> Self {
+        Self {
+            re: T::one(),
+            im: T::zero(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::scalar::ComplexField;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -1.0);
+
+        assert_eq!(a + b, Complex::new(4.0, 1.0));
+        assert_eq!(a - b, Complex::new(-2.0, 3.0));
+        assert_eq!(a * b, Complex::new(5.0, 5.0));
+        assert_eq!(-a, Complex::new(-1.0, -2.0));
+        assert_eq!(a.conj(), Complex::new(1.0, -2.0));
+
+        let q = (a * b) / b;
+        assert_relative_eq!(q.re, a.re);
+        assert_relative_eq!(q.im, a.im);
+
+        let mut c = a;
+        c *= b;
+        c -= b;
+        c /= a;
+        c += a;
+        assert_relative_eq!(c.re, 3.8);
+        assert_relative_eq!(c.im, 2.4);
+    }
+
+    #[test]
+    fn test_division_range() {
+        // The squared modulus of the divisor overflows or underflows
+        let big = Complex::new(1e300f64, 1e300);
+        assert_eq!(big / big, Complex::new(1.0, 0.0));
+        let q = Complex::new(1.0f64, 0.0) / Complex::new(0.0, 1e300);
+        assert_relative_eq!(q.im, -1e-300, max_relative = 1e-15);
+        assert_eq!(q.re, 0.0);
+
+        let q = Complex::new(1e-300f64, 1e-300) / Complex::new(1e-300, 2e-300);
+        assert_relative_eq!(q.re, 0.6, max_relative = 1e-15);
+        assert_relative_eq!(q.im, -0.2, max_relative = 1e-15);
+        let q = Complex::new(3e-30f32, 0.0) / Complex::new(4e-30, 3e-30);
+        assert_relative_eq!(q.re, 0.48, max_relative = 1e-6);
+        assert_relative_eq!(q.im, -0.36, max_relative = 1e-6);
+    }
+
+    #[test]
+    fn test_modulus() {
+        let a = Complex::new(3.0f64, -4.0);
+        assert_eq!(a.norm_sqr(), 25.0);
+        assert_relative_eq!(a.abs(), 5.0);
+        assert_relative_eq!(Complex::new(0.0f32, 1.0).arg(), std::f32::consts::FRAC_PI_2);
+        assert_eq!(Complex::from(2.0f64), Complex::new(2.0, 0.0));
+    }
+
+    #[test]
+    fn test_complex_field() {
+        let z = Complex::new(3.0f64, -4.0);
+        assert_eq!(ComplexField::conj(z), z.conj());
+        assert_eq!(z.re(), 3.0);
+        assert_eq!(z.modulus_sqr(), 25.0);
+        assert_relative_eq!(z.modulus(), 5.0);
+        assert_eq!(Complex::<f64>::from_real(2.0), Complex::new(2.0, 0.0));
+
+        // The squared modulus overflows but the modulus does not
+        let big = Complex::new(3e200f64, 4e200);
+        assert_relative_eq!(big.modulus(), 5e200, max_relative = 1e-15);
+        assert_eq!(Complex::<f64>::zero().modulus(), 0.0);
+
+        let zs = [Complex::one(), z, Complex::new(0.0, 2.0)];
+        assert_eq!(
+            zs.into_iter().sum::<Complex<f64>>(),
+            Complex::new(4.0, -2.0)
+        );
+        assert!(Complex::<f32>::zero().is_zero());
+    }
+}
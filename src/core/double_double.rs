@@ -0,0 +1,350 @@
+//! Double-double arithmetic, representing a number as the unevaluated sum of two `f64`.
+//!
+//! The algorithms follow Hida, Li and Bailey's QD library and give about 106 bits of precision,
+//! twice that of `f64`, at a fraction of the cost of arbitrary precision. The exponent range is
+//! that of `f64`.
+
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::core::scalar::{One, RealField, Zero};
+
+/// Floating-point number `hi + lo` with `|lo| <= ulp(hi) / 2`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+pub struct DoubleDouble {
+    hi: f64,
+    lo: f64,
+}
+
+/// `ln(2)` to double-double precision.
+const LN_2: DoubleDouble = DoubleDouble {
+    hi: std::f64::consts::LN_2,
+    lo: 2.3190468138462996e-17,
+};
+
+/// Computes `a + b` and its rounding error.
+fn two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+    let bb = s - a;
+
+    (s, (a - (s - bb)) + (b - bb))
+}
+
+/// Computes `a + b` and its rounding error, assuming `|a| >= |b|`.
+fn quick_two_sum(a: f64, b: f64) -> (f64, f64) {
+    let s = a + b;
+
+    (s, b - (s - a))
+}
+
+/// Computes `a * b` and its rounding error.
+fn two_prod(a: f64, b: f64) -> (f64, f64) {
+    let p = a * b;
+
+    (p, a.mul_add(b, -p))
+}
+
+impl DoubleDouble {
+    /// Normalizes `hi + lo`, which may be given in any order of magnitude.
+    pub fn new(hi: f64, lo: f64) -> Self {
+        let (hi, lo) = two_sum(hi, lo);
+
+        Self { hi, lo }
+    }
+
+    /// Leading part, the `f64` nearest to the number.
+    pub fn hi(self) -> f64 {
+        self.hi
+    }
+
+    /// Trailing part, the rounding error of `hi`.
+    pub fn lo(self) -> f64 {
+        self.lo
+    }
+
+    fn renormalize((hi, lo): (f64, f64)) -> Self {
+        let (hi, lo) = quick_two_sum(hi, lo);
+
+        Self { hi, lo }
+    }
+
+    fn mul_f64(self, b: f64) -> Self {
+        let (p1, p2) = two_prod(self.hi, b);
+
+        Self::renormalize((p1, p2 + self.lo * b))
+    }
+
+    /// Multiplies by `2^n`, exactly unless the result overflows or becomes subnormal.
+    fn scale(self, n: i32) -> Self {
+        // In two steps, as 2^n itself may not be representable when the result is
+        let (f, g) = (2f64.powi(n / 2), 2f64.powi(n - n / 2));
+
+        Self {
+            hi: self.hi * f * g,
+            lo: self.lo * f * g,
+        }
+    }
+
+    pub fn exp(self) -> Self {
+        if self.hi > 709.8 {
+            return f64::INFINITY.into();
+        }
+        if self.hi < -745.2 {
+            return Self::zero();
+        }
+
+        // exp(x) = 2^m * exp(r)^512 with x = m * ln(2) + 512 * r, and exp(r) - 1 from its
+        // Taylor series, which converges fast for |r| < ln(2) / 1024
+        let m = (self.hi / LN_2.hi).round();
+        let r = (self - LN_2.mul_f64(m)).scale(-9);
+
+        let mut s = r;
+        let mut term = r;
+        for k in 2..30 {
+            term = term * r / Self::from(k as f64);
+            s += term;
+            if term.hi.abs() <= f64::EPSILON * f64::EPSILON * s.hi.abs() {
+                break;
+            }
+        }
+        // (1 + s)^2 - 1 = s * (2 + s)
+        for _ in 0..9 {
+            s = s * (s + Self::from(2.0));
+        }
+
+        (s + Self::one()).scale(m as i32)
+    }
+}
+
+impl From<f64> for DoubleDouble {
+    fn from(hi: f64) -> Self {
+        Self { hi, lo: 0.0 }
+    }
+}
+
+impl From<DoubleDouble> for f64 {
+    fn from(x: DoubleDouble) -> Self {
+        x.hi
+    }
+}
+
+impl Add for DoubleDouble {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        let (s1, s2) = two_sum(self.hi, other.hi);
+        let (t1, t2) = two_sum(self.lo, other.lo);
+        let (s1, s2) = quick_two_sum(s1, s2 + t1);
+
+        Self::renormalize((s1, s2 + t2))
+    }
+}
+
+impl Sub for DoubleDouble {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        self + -other
+    }
+}
+
+impl Mul for DoubleDouble {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self::Output {
+        let (p1, p2) = two_prod(self.hi, other.hi);
+
+        Self::renormalize((p1, p2 + (self.hi * other.lo + self.lo * other.hi)))
+    }
+}
+
+impl Div for DoubleDouble {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self::Output {
+        // Long division, one f64 digit of the quotient at a time
+        let q1 = self.hi / other.hi;
+        let r = self - other.mul_f64(q1);
+        let q2 = r.hi / other.hi;
+        let r = r - other.mul_f64(q2);
+        let q3 = r.hi / other.hi;
+
+        Self::renormalize((q1, q2)) + Self::from(q3)
+    }
+}
+
+impl Neg for DoubleDouble {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+impl AddAssign for DoubleDouble {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for DoubleDouble {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for DoubleDouble {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl DivAssign for DoubleDouble {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+impl Sum for DoubleDouble {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), |acc, x| acc + x)
+    }
+}
+
+impl Zero for DoubleDouble {
+    fn zero() -> Self {
+        Self { hi: 0.0, lo: 0.0 }
+    }
+
+    fn is_zero(&self) -> bool {
+        self.hi == 0.0
+    }
+}
+
+impl One for DoubleDouble {
+    fn one() -> Self {
+        Self { hi: 1.0, lo: 0.0 }
+    }
+}
+
+impl RealField for DoubleDouble {
+    /// `2^-104`, the spacing of double-double numbers near 1.
+    fn epsilon() -> Self {
+        Self::from(f64::EPSILON * f64::EPSILON)
+    }
+
+    fn abs(self) -> Self {
+        if self.hi < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn sqrt(self) -> Self {
+        if self.hi <= 0.0 {
+            return Self::from(self.hi.sqrt());
+        }
+
+        // One Newton step from the f64 square root, which doubles its precision
+        let x = 1.0 / self.hi.sqrt();
+        let ax = self.hi * x;
+        let ax2 = Self::renormalize(two_prod(ax, ax));
+
+        Self::from(ax) + Self::from((self - ax2).hi * (x * 0.5))
+    }
+
+    fn ln(self) -> Self {
+        if self.hi <= 0.0 {
+            return Self::from(self.hi.ln());
+        }
+
+        // One Newton step on exp(y) = x from the f64 logarithm
+        let y = Self::from(self.hi.ln());
+
+        y + self * (-y).exp() - Self::one()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::core::matrix::Matrix;
+    use crate::core::vector::Vector;
+    use crate::linalg::lu::LU;
+
+    fn dd(x: f64) -> DoubleDouble {
+        DoubleDouble::from(x)
+    }
+
+    fn assert_close(x: DoubleDouble, y: DoubleDouble, tol: f64) {
+        let err = (x - y).abs().hi();
+        assert!(err <= tol * y.abs().hi(), "{x:?} != {y:?}, error {err:e}");
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let third = dd(1.0) / dd(3.0);
+        assert!(third.lo() != 0.0);
+        assert_close(third * dd(3.0), dd(1.0), 1e-31);
+        assert_close(third + third + third - dd(1.0) + dd(1.0), dd(1.0), 1e-31);
+
+        // 0.1 + 0.2 - 0.3 is about 5.6e-17 in f64
+        let (a, b, c) = (dd(1.0) / dd(10.0), dd(2.0) / dd(10.0), dd(3.0) / dd(10.0));
+        assert!((a + b - c).abs().hi() < 1e-32);
+
+        let mut x = dd(2.0);
+        x *= dd(5.0);
+        x /= dd(4.0);
+        x -= dd(0.5);
+        x += dd(1.0);
+        assert_eq!(x, dd(3.0));
+        assert_eq!(-x, DoubleDouble::new(-3.0, 0.0));
+        assert_eq!([dd(1.0), dd(2.0)].into_iter().sum::<DoubleDouble>(), x);
+        assert!(dd(1.0) < dd(1.0) + DoubleDouble::epsilon());
+    }
+
+    #[test]
+    fn test_elementary_functions() {
+        let sqrt2 = dd(2.0).sqrt();
+        assert_eq!(sqrt2.hi(), std::f64::consts::SQRT_2);
+        assert_relative_eq!(sqrt2.lo(), -9.667293313452913e-17, max_relative = 1e-15);
+        assert_close(sqrt2 * sqrt2, dd(2.0), 1e-31);
+
+        let e = dd(1.0).exp();
+        assert_eq!(e.hi(), std::f64::consts::E);
+        assert_relative_eq!(e.lo(), 1.4456468917292502e-16, max_relative = 1e-15);
+        assert_close(e.ln(), dd(1.0), 1e-31);
+        assert_close(dd(1e-300).ln().exp(), dd(1e-300), 1e-30);
+        assert_close(dd(-20.5).exp().ln(), dd(-20.5), 1e-31);
+
+        assert_eq!(dd(0.0).exp(), dd(1.0));
+        assert_close(dd(709.5).exp(), dd(709.5f64.exp()), 1e-15);
+        assert_eq!(dd(1000.0).exp().hi(), f64::INFINITY);
+        assert!(dd(-1.0).sqrt().hi().is_nan());
+    }
+
+    #[test]
+    fn test_lu_hilbert() {
+        // The Hilbert matrix of order 8 has condition number about 1.5e10, so f64 loses about
+        // ten of its sixteen digits while double-double keeps about twenty
+        let n = 8;
+        let hilbert = Matrix::from_fn(n, n, |i, j| dd(1.0) / dd((i + j + 1) as f64));
+        let b = Vector::from_fn(n, |i| (0..n).map(|j| hilbert[(i, j)]).sum());
+
+        let x = hilbert.lu().unwrap().solve(&b);
+        for i in 0..n {
+            assert_close(x[i], dd(1.0), 1e-18);
+        }
+
+        let hilbert = Matrix::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+        let b = Vector::from_fn(n, |i| (0..n).map(|j| hilbert[(i, j)]).sum());
+        let x = hilbert.lu().unwrap().solve(&b);
+        assert!((0..n).any(|i| (x[i] - 1.0).abs() > 1e-10));
+    }
+}
@@ -12,4 +12,69 @@ pub enum CholDecompositionError {
 pub enum LUDecompositionError {
     #[error("LU decomposition is only implemented for square matrices")]
     NotSquareError,
+    #[error("matrix is singular")]
+    SingularMatrixError,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum QRDecompositionError {
+    #[error("matrix does not have full column rank")]
+    RankDeficientError,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum LeastSquaresError {
+    #[error("matrix does not have full column rank")]
+    RankDeficientError,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum SVDecompositionError {
+    #[error("SVD did not converge")]
+    NoConvergenceError,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum EigenDecompositionError {
+    #[error("eigendecomposition is only implemented for square matrices")]
+    NotSquareError,
+    #[error("matrix is not symmetric")]
+    NotSymmetricError,
+    #[error("eigenvalue iteration did not converge")]
+    NoConvergenceError,
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ShapeError {
+    #[error("expected {expected} elements, got {got}")]
+    LengthMismatchError { expected: usize, got: usize },
+    #[error("expected rows or columns of length {expected}, got one of length {got}")]
+    RaggedError { expected: usize, got: usize },
+    #[error("operands have incompatible dimensions {lhs:?} and {rhs:?}")]
+    DimensionMismatchError {
+        lhs: (usize, usize),
+        rhs: (usize, usize),
+    },
+    #[error("matrix is not triangular")]
+    NotTriangularError,
+}
+
+/// Any error returned by this crate, so that callers mixing several operations can propagate
+/// them all with `?`.
+#[derive(Error, Debug, PartialEq)]
+pub enum Error {
+    #[error(transparent)]
+    ShapeError(#[from] ShapeError),
+    #[error(transparent)]
+    CholDecompositionError(#[from] CholDecompositionError),
+    #[error(transparent)]
+    LUDecompositionError(#[from] LUDecompositionError),
+    #[error(transparent)]
+    QRDecompositionError(#[from] QRDecompositionError),
+    #[error(transparent)]
+    LeastSquaresError(#[from] LeastSquaresError),
+    #[error(transparent)]
+    SVDecompositionError(#[from] SVDecompositionError),
+    #[error(transparent)]
+    EigenDecompositionError(#[from] EigenDecompositionError),
 }
@@ -2,12 +2,21 @@ use std::default::Default;
 use std::iter::Sum;
 use std::ops::{Add, AddAssign, Mul};
 
-use crate::core::matrix::Matrix;
+use crate::core::error::ShapeError;
+use crate::core::error::ShapeError::DimensionMismatchError;
+use crate::core::kernel::gemm_blocked;
+use crate::core::matrix::{Layout, Matrix};
+use crate::core::parallel::{for_each_row_block, rows_per_block, MaybeSync};
+use crate::core::scalar::{ComplexField, RealField};
+use crate::core::simd::{self, cast, cast_mut, cast_scalar};
 use crate::core::vector::Vector;
+use crate::core::view::{AsView, AsViewMut, MatrixView, MatrixViewMut};
 
+/// Computes the inner product of two vectors, with SIMD kernels for `f32` and `f64`. Complex
+/// vectors are not conjugated, see `dotc`.
 pub fn dot<T>(lhs: &Vector<T>, rhs: &Vector<T>) -> T
 where
-    T: Mul<Output = T> + Copy + Sum,
+    T: Mul<Output = T> + Copy + Sum + 'static,
 {
     if lhs.n != rhs.n {
         panic!(
@@ -16,6 +25,13 @@ where
         );
     }
 
+    if let (Some(x), Some(y)) = (cast::<T, f64>(&lhs.data), cast::<T, f64>(&rhs.data)) {
+        return cast_scalar(simd::dot(x, y)).unwrap();
+    }
+    if let (Some(x), Some(y)) = (cast::<T, f32>(&lhs.data), cast::<T, f32>(&rhs.data)) {
+        return cast_scalar(simd::dot(x, y)).unwrap();
+    }
+
     lhs.data
         .iter()
         .zip(rhs.data.iter())
@@ -23,60 +39,431 @@ where
         .sum()
 }
 
-pub fn gemv<T>(lhs: &Matrix<T>, rhs: &Vector<T>) -> Vector<T>
+/// Computes the inner product of two vectors, or fails if their lengths differ.
+pub fn try_dot<T>(lhs: &Vector<T>, rhs: &Vector<T>) -> Result<T, ShapeError>
+where
+    T: Mul<Output = T> + Copy + Sum + 'static,
+{
+    lhs.check_same_len(rhs)?;
+
+    Ok(dot(lhs, rhs))
+}
+
+/// Computes the conjugated inner product `sum(conj(lhs[i]) * rhs[i])`, the inner product of
+/// complex vectors. Equal to `dot` for real vectors.
+pub fn dotc<T>(lhs: &Vector<T>, rhs: &Vector<T>) -> T
 where
-    T: Add<Output = T> + AddAssign + Mul<Output = T> + Copy + Default,
+    T: ComplexField,
 {
-    if lhs.n_cols != rhs.n {
+    if lhs.n != rhs.n {
+        panic!(
+            "Vectors must have the same dimensions. Got {} and {}.",
+            lhs.n, rhs.n
+        );
+    }
+
+    let mut s = T::zero();
+    for (x, y) in lhs.data.iter().zip(rhs.data.iter()) {
+        s += x.conj() * *y;
+    }
+
+    s
+}
+
+/// Computes `y += alpha * x`, with SIMD kernels for `f32` and `f64`.
+pub fn axpy<T>(alpha: T, x: &Vector<T>, y: &mut Vector<T>)
+where
+    T: Mul<Output = T> + AddAssign + Copy + 'static,
+{
+    if x.n != y.n {
+        panic!(
+            "Vectors must have the same dimensions. Got {} and {}.",
+            x.n, y.n
+        );
+    }
+
+    if let (Some(a), Some(xs), Some(ys)) = (
+        cast_scalar::<T, f64>(alpha),
+        cast::<T, f64>(&x.data),
+        cast_mut::<T, f64>(&mut y.data),
+    ) {
+        return simd::axpy(a, xs, ys);
+    }
+    if let (Some(a), Some(xs), Some(ys)) = (
+        cast_scalar::<T, f32>(alpha),
+        cast::<T, f32>(&x.data),
+        cast_mut::<T, f32>(&mut y.data),
+    ) {
+        return simd::axpy(a, xs, ys);
+    }
+
+    for (&xi, yi) in x.data.iter().zip(y.data.iter_mut()) {
+        *yi += alpha * xi;
+    }
+}
+
+pub fn gemv<T, A>(lhs: &A, rhs: &Vector<T>) -> Vector<T>
+where
+    T: Add<Output = T> + AddAssign + Mul<Output = T> + Copy + Default + MaybeSync,
+    A: AsView<Elem = T> + ?Sized,
+{
+    let a = lhs.as_view();
+    if a.n_cols != rhs.n {
         panic!(
             "Matrix-vector product with incompatible dimensions: {:?} and {:?}",
-            (lhs.n_rows, lhs.n_cols),
+            (a.n_rows, a.n_cols),
             rhs.n
         );
     }
 
-    let mut result = Vector::new(lhs.n_rows);
-    for i in 0..lhs.n_rows {
-        result[i] = T::default();
-        for j in 0..lhs.n_cols {
-            result[i] += lhs[(i, j)] * rhs[j];
-        }
-    }
+    let mut result = Vector::new(a.n_rows);
+    for_each_row_block(
+        &mut result.data,
+        1,
+        rows_per_block(a.n_cols),
+        |row, block| {
+            for (i, ri) in block.iter_mut().enumerate() {
+                for (j, &x) in rhs.data.iter().enumerate() {
+                    *ri += a.at(row + i, j) * x;
+                }
+            }
+        },
+    );
     result
 }
 
-pub fn gemm<T>(lhs: &Matrix<T>, rhs: &Matrix<T>) -> Matrix<T>
+/// Computes the matrix-vector product `lhs * rhs`, or fails if the dimensions are incompatible.
+pub fn try_gemv<T, A>(lhs: &A, rhs: &Vector<T>) -> Result<Vector<T>, ShapeError>
+where
+    T: Add<Output = T> + AddAssign + Mul<Output = T> + Copy + Default + MaybeSync,
+    A: AsView<Elem = T> + ?Sized,
+{
+    let a = lhs.as_view();
+    if a.n_cols != rhs.n {
+        return Err(DimensionMismatchError {
+            lhs: (a.n_rows, a.n_cols),
+            rhs: (rhs.n, 1),
+        });
+    }
+
+    Ok(gemv(lhs, rhs))
+}
+
+/// Computes the matrix product `lhs * rhs` of two matrices or views.
+///
+/// `f32` and `f64` operands go through a packed, cache-blocked kernel; any other element type
+/// uses a plain row-by-row loop.
+pub fn gemm<T, A, B>(lhs: &A, rhs: &B) -> Matrix<T>
 where
-    T: Add<Output = T> + AddAssign + Mul<Output = T> + Copy + Default,
+    T: Add<Output = T> + AddAssign + Mul<Output = T> + Copy + Default + MaybeSync + 'static,
+    A: AsView<Elem = T> + ?Sized,
+    B: AsView<Elem = T> + ?Sized,
 {
-    if lhs.n_cols != rhs.n_rows {
+    let (a, b) = (lhs.as_view(), rhs.as_view());
+    if a.n_cols != b.n_rows {
         panic!(
             "Matrices have incompatible dimensions: {:?} and {:?}",
-            (lhs.n_rows, lhs.n_cols),
-            (rhs.n_rows, rhs.n_cols)
+            (a.n_rows, a.n_cols),
+            (b.n_rows, b.n_cols)
         );
     }
 
-    let mut data = vec![T::default(); lhs.n_rows * rhs.n_cols];
-    data.chunks_mut(lhs.n_rows)
-        .enumerate()
-        .for_each(|(i, c_row)| {
-            for k in 0..lhs.n_cols {
-                for j in 0..rhs.n_cols {
-                    c_row[j] += lhs[(i, k)] * rhs[(k, j)];
+    let (m, k, n) = (a.n_rows, a.n_cols, b.n_cols);
+    let mut data = vec![T::default(); m * n];
+    if !gemm_float::<T, f32>(a, b, &mut data) && !gemm_float::<T, f64>(a, b, &mut data) {
+        for_each_row_block(&mut data, n, rows_per_block(n * k), |row, block| {
+            for (i, c_row) in block.chunks_exact_mut(n).enumerate() {
+                for p in 0..k {
+                    let a_ip = a.at(row + i, p);
+                    for (j, c) in c_row.iter_mut().enumerate() {
+                        *c += a_ip * b.at(p, j);
+                    }
                 }
             }
         });
+    }
 
     Matrix {
-        n_rows: lhs.n_rows,
-        n_cols: rhs.n_cols,
+        n_rows: m,
+        n_cols: n,
         data,
+        layout: Layout::RowMajor,
+    }
+}
+
+/// Computes the matrix product `lhs * rhs`, or fails if the dimensions are incompatible.
+pub fn try_gemm<T, A, B>(lhs: &A, rhs: &B) -> Result<Matrix<T>, ShapeError>
+where
+    T: Add<Output = T> + AddAssign + Mul<Output = T> + Copy + Default + MaybeSync + 'static,
+    A: AsView<Elem = T> + ?Sized,
+    B: AsView<Elem = T> + ?Sized,
+{
+    let (a, b) = (lhs.as_view(), rhs.as_view());
+    if a.n_cols != b.n_rows {
+        return Err(DimensionMismatchError {
+            lhs: (a.n_rows, a.n_cols),
+            rhs: (b.n_rows, b.n_cols),
+        });
+    }
+
+    Ok(gemm(lhs, rhs))
+}
+
+/// Runs the blocked kernel if `T` is the float type `F`, returning whether it did.
+fn gemm_float<T, F>(a: MatrixView<T>, b: MatrixView<T>, c: &mut [T]) -> bool
+where
+    T: 'static,
+    F: RealField,
+{
+    let (m, n) = (a.n_rows, b.n_cols);
+    let (Some(a), Some(b), Some(c)) = (a.cast::<F>(), b.cast::<F>(), cast_mut::<T, F>(c)) else {
+        return false;
+    };
+
+    gemm_blocked(
+        F::one(),
+        a,
+        b,
+        F::zero(),
+        &mut MatrixViewMut::row_major(c, m, n),
+    );
+
+    true
+}
+
+/// Transposition applied to an operand of `gemm_into` and `gemv_into`.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum Op {
+    #[default]
+    Identity,
+    Transpose,
+}
+
+impl Op {
+    fn apply<T>(self, view: MatrixView<T>) -> MatrixView<T> {
+        match self {
+            Op::Identity => view,
+            Op::Transpose => view.t(),
+        }
+    }
+}
+
+/// Computes `c = alpha * op_a(a) * op_b(b) + beta * c` in place, where any operand may be a
+/// view. Transposed operands are read through strides and never copied. As in BLAS, `c` is not
+/// read when `beta` is zero, so it may hold uninitialized garbage such as NaNs.
+pub fn gemm_into<T, A, B, C>(alpha: T, a: &A, op_a: Op, b: &B, op_b: Op, beta: T, c: &mut C)
+where
+    T: RealField,
+    A: AsView<Elem = T> + ?Sized,
+    B: AsView<Elem = T> + ?Sized,
+    C: AsViewMut<Elem = T> + ?Sized,
+{
+    let (a, b, mut c) = (
+        op_a.apply(a.as_view()),
+        op_b.apply(b.as_view()),
+        c.as_view_mut(),
+    );
+    if a.n_cols != b.n_rows || c.n_rows != a.n_rows || c.n_cols != b.n_cols {
+        panic!(
+            "Matrices have incompatible dimensions: {:?}, {:?} and {:?}",
+            (a.n_rows, a.n_cols),
+            (b.n_rows, b.n_cols),
+            (c.n_rows, c.n_cols)
+        );
+    }
+
+    gemm_blocked(alpha, a, b, beta, &mut c);
+}
+
+/// Computes `y = alpha * op_a(a) * x + beta * y` in place. As in BLAS, `y` is not read when
+/// `beta` is zero.
+pub fn gemv_into<T, A>(alpha: T, a: &A, op_a: Op, x: &Vector<T>, beta: T, y: &mut Vector<T>)
+where
+    T: RealField,
+    A: AsView<Elem = T> + ?Sized,
+{
+    let a = op_a.apply(a.as_view());
+    if a.n_cols != x.n || a.n_rows != y.n {
+        panic!(
+            "Matrix-vector product with incompatible dimensions: {:?}, {:?} and {:?}",
+            (a.n_rows, a.n_cols),
+            x.n,
+            y.n
+        );
+    }
+
+    for_each_row_block(&mut y.data, 1, rows_per_block(x.n), |row, block| {
+        for (i, yi) in block.iter_mut().enumerate() {
+            let mut s = T::zero();
+            for (j, &xj) in x.data.iter().enumerate() {
+                s += a.at(row + i, j) * xj;
+            }
+            *yi = if beta == T::zero() {
+                alpha * s
+            } else {
+                alpha * s + beta * *yi
+            };
+        }
+    });
+}
+
+/// Computes the matrix product `lhs * rhs` with the Strassen-Winograd algorithm, which needs
+/// seven half-size products per level instead of eight.
+///
+/// The recursion falls back to the blocked `gemm` kernel once any dimension is at most
+/// `crossover`; a few hundred is a good choice, as smaller blocks lose more to the extra
+/// additions than they save. Against the blocked kernel this only pays off for matrices with
+/// dimensions in the thousands. Odd dimensions are handled by peeling off the last row, column or
+/// inner index and fixing up the result afterwards. The error bound is somewhat weaker than
+/// for the standard algorithm.
+pub fn gemm_strassen<T, A, B>(lhs: &A, rhs: &B, crossover: usize) -> Matrix<T>
+where
+    T: RealField,
+    A: AsView<Elem = T> + ?Sized,
+    B: AsView<Elem = T> + ?Sized,
+{
+    let (a, b) = (lhs.as_view(), rhs.as_view());
+    if a.n_cols != b.n_rows {
+        panic!(
+            "Matrices have incompatible dimensions: {:?} and {:?}",
+            (a.n_rows, a.n_cols),
+            (b.n_rows, b.n_cols)
+        );
+    }
+
+    Matrix {
+        n_rows: a.n_rows,
+        n_cols: b.n_cols,
+        data: strassen(a, b, crossover.max(1)),
+        layout: Layout::RowMajor,
+    }
+}
+
+/// Returns `a * b` as a dense row-major buffer.
+fn strassen<T>(a: MatrixView<T>, b: MatrixView<T>, crossover: usize) -> Vec<T>
+where
+    T: RealField,
+{
+    let (m, k, n) = (a.n_rows, a.n_cols, b.n_cols);
+    let mut c = vec![T::zero(); m * n];
+    if m.min(k).min(n) <= crossover {
+        gemm_blocked(
+            T::one(),
+            a,
+            b,
+            T::zero(),
+            &mut MatrixViewMut::row_major(&mut c, m, n),
+        );
+        return c;
+    }
+
+    let (mh, kh, nh) = (m / 2, k / 2, n / 2);
+    let (a11, a12) = (a.block(0, 0, mh, kh), a.block(0, kh, mh, kh));
+    let (a21, a22) = (a.block(mh, 0, mh, kh), a.block(mh, kh, mh, kh));
+    let (b11, b12) = (b.block(0, 0, kh, nh), b.block(0, nh, kh, nh));
+    let (b21, b22) = (b.block(kh, 0, kh, nh), b.block(kh, nh, kh, nh));
+
+    let s1 = add(a21, a22);
+    let s1 = MatrixView::row_major(&s1, mh, kh);
+    let s2 = sub(s1, a11);
+    let s2 = MatrixView::row_major(&s2, mh, kh);
+    let s3 = sub(a11, a21);
+    let s3 = MatrixView::row_major(&s3, mh, kh);
+    let s4 = sub(a12, s2);
+    let s4 = MatrixView::row_major(&s4, mh, kh);
+
+    let t1 = sub(b12, b11);
+    let t1 = MatrixView::row_major(&t1, kh, nh);
+    let t2 = sub(b22, t1);
+    let t2 = MatrixView::row_major(&t2, kh, nh);
+    let t3 = sub(b22, b12);
+    let t3 = MatrixView::row_major(&t3, kh, nh);
+    let t4 = sub(t2, b21);
+    let t4 = MatrixView::row_major(&t4, kh, nh);
+
+    let m1 = strassen(a11, b11, crossover);
+    let m2 = strassen(a12, b21, crossover);
+    let m3 = strassen(s4, b22, crossover);
+    let m4 = strassen(a22, t4, crossover);
+    let m5 = strassen(s1, t1, crossover);
+    let m6 = strassen(s2, t2, crossover);
+    let m7 = strassen(s3, t3, crossover);
+
+    // C11 = M1 + M2, C12 = U2 + M5 + M3, C21 = U2 + M7 - M4, C22 = U2 + M7 + M5, where
+    // U2 = M1 + M6
+    for i in 0..mh {
+        for j in 0..nh {
+            let h = i * nh + j;
+            let u2 = m1[h] + m6[h];
+            let u3 = u2 + m7[h];
+            c[i * n + j] = m1[h] + m2[h];
+            c[i * n + nh + j] = u2 + m5[h] + m3[h];
+            c[(mh + i) * n + j] = u3 - m4[h];
+            c[(mh + i) * n + nh + j] = u3 + m5[h];
+        }
+    }
+
+    // Fix up the peeled parts of odd dimensions
+    let mut c_view = MatrixViewMut::row_major(&mut c, m, n);
+    let (me, ke, ne) = (2 * mh, 2 * kh, 2 * nh);
+    if ke < k {
+        let a_col = a.block(0, ke, me, 1);
+        let b_row = b.block(ke, 0, 1, ne);
+        gemm_blocked(
+            T::one(),
+            a_col,
+            b_row,
+            T::one(),
+            &mut c_view.block_mut(0, 0, me, ne),
+        );
+    }
+    if ne < n {
+        let b_col = b.block(0, ne, k, 1);
+        gemm_blocked(
+            T::one(),
+            a.block(0, 0, me, k),
+            b_col,
+            T::zero(),
+            &mut c_view.block_mut(0, ne, me, 1),
+        );
+    }
+    if me < m {
+        gemm_blocked(
+            T::one(),
+            a.block(me, 0, 1, k),
+            b,
+            T::zero(),
+            &mut c_view.block_mut(me, 0, 1, n),
+        );
+    }
+
+    c
+}
+
+fn add<T: RealField>(x: MatrixView<T>, y: MatrixView<T>) -> Vec<T> {
+    let mut z = Vec::with_capacity(x.n_rows * x.n_cols);
+    for i in 0..x.n_rows {
+        for j in 0..x.n_cols {
+            z.push(x.at(i, j) + y.at(i, j));
+        }
     }
+    z
+}
+
+fn sub<T: RealField>(x: MatrixView<T>, y: MatrixView<T>) -> Vec<T> {
+    let mut z = Vec::with_capacity(x.n_rows * x.n_cols);
+    for i in 0..x.n_rows {
+        for j in 0..x.n_cols {
+            z.push(x.at(i, j) - y.at(i, j));
+        }
+    }
+    z
 }
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
     #[test]
@@ -105,4 +492,310 @@ mod tests {
         assert_eq!(c[(1, 0)], 8);
         assert_eq!(c[(1, 1)], 14);
     }
+
+    fn naive(a: &Matrix<f64>, b: &Matrix<f64>) -> Matrix<f64> {
+        let mut c = Matrix::<f64>::zeros(a.n_rows, b.n_cols);
+        for i in 0..a.n_rows {
+            for j in 0..b.n_cols {
+                for k in 0..a.n_cols {
+                    c[(i, j)] += a[(i, k)] * b[(k, j)];
+                }
+            }
+        }
+        c
+    }
+
+    #[test]
+    fn matmul_blocked_edges() {
+        // Shapes that cross the MC and KC block boundaries and leave partial microtiles
+        let a = Matrix::from_gen(131, 263, |i, j| ((i * 7 + j * 3) % 11) as f64 - 5.0);
+        let b = Matrix::from_gen(263, 6, |i, j| ((i + j * 5) % 13) as f64 / 4.0);
+
+        let c = gemm(&a, &b);
+        let expected = naive(&a, &b);
+        assert_eq!((c.n_rows, c.n_cols), (131, 6));
+        for i in 0..131 {
+            for j in 0..6 {
+                assert_eq!(c[(i, j)], expected[(i, j)]);
+            }
+        }
+    }
+
+    #[test]
+    fn matmul_f32() {
+        let a: Matrix<f32> = Matrix::from_gen(3, 5, |i, j| (i + 2 * j) as f32);
+        let b: Matrix<f32> = Matrix::from_gen(5, 2, |i, j| i as f32 - j as f32);
+
+        let c = gemm(&a, &b);
+        assert_eq!((c.n_rows, c.n_cols), (3, 2));
+        assert_eq!(c[(0, 0)], 60.0);
+        assert_eq!(c[(2, 1)], 50.0);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_matmul_rectangular(
+            (m, k, n, a, b) in (1usize..40, 0usize..40, 1usize..40).prop_flat_map(|(m, k, n)| {
+                (
+                    Just(m),
+                    Just(k),
+                    Just(n),
+                    prop::collection::vec(-1.0..1.0, m * k),
+                    prop::collection::vec(-1.0..1.0, k * n),
+                )
+            })
+        ) {
+            let a = Matrix { n_rows: m, n_cols: k, data: a, layout: Layout::RowMajor };
+            let b = Matrix { n_rows: k, n_cols: n, data: b, layout: Layout::RowMajor };
+
+            let c = gemm(&a, &b);
+            let expected = naive(&a, &b);
+            prop_assert_eq!((c.n_rows, c.n_cols), (m, n));
+            for i in 0..m {
+                for j in 0..n {
+                    prop_assert!((c[(i, j)] - expected[(i, j)]).abs() <= 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gemm_into_ops() {
+        let a = Matrix::from_gen(3, 4, |i, j| (i * 4 + j) as f64 - 5.0);
+        let b = Matrix::from_gen(4, 2, |i, j| (i + 3 * j) as f64 / 2.0);
+        let expected = gemm(&a, &b);
+
+        let (at, bt) = (a.transpose(), b.transpose());
+        for (lhs, op_a) in [(&a, Op::Identity), (&at, Op::Transpose)] {
+            for (rhs, op_b) in [(&b, Op::Identity), (&bt, Op::Transpose)] {
+                let mut c = Matrix::<f64>::ones(3, 2);
+                gemm_into(2.0, lhs, op_a, rhs, op_b, -1.0, &mut c);
+                for i in 0..3 {
+                    for j in 0..2 {
+                        assert_eq!(c[(i, j)], 2.0 * expected[(i, j)] - 1.0);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gemm_into_beta_zero() {
+        let a: Matrix<f32> = Matrix::from_gen(2, 2, |i, j| (i + j) as f32);
+        let mut c: Matrix<f32> = Matrix::from_gen(2, 2, |_, _| f32::NAN);
+
+        gemm_into(1.0, &a, Op::Transpose, &a, Op::Identity, 0.0, &mut c);
+        assert_eq!(c[(0, 0)], 1.0);
+        assert_eq!(c[(0, 1)], 2.0);
+        assert_eq!(c[(1, 0)], 2.0);
+        assert_eq!(c[(1, 1)], 5.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gemm_into_dims() {
+        let a = Matrix::<f64>::ones(2, 3);
+        let mut c = Matrix::<f64>::zeros(2, 2);
+
+        gemm_into(1.0, &a, Op::Identity, &a, Op::Identity, 0.0, &mut c);
+    }
+
+    #[test]
+    fn gemm_views() {
+        let a = Matrix::from_gen(7, 9, |i, j| (i * 9 + j) as f64 - 30.0);
+        let b = Matrix::from_gen(9, 6, |i, j| ((i + 2 * j) % 5) as f64);
+
+        // Products of sub-blocks and transposed views match products of copies
+        let (lhs, rhs) = (a.view(1..6, 2..), b.view(2.., ..4));
+        let c = gemm(&lhs, &rhs);
+        let expected = naive(&lhs.to_matrix(), &rhs.to_matrix());
+        assert_eq!(c.data, expected.data);
+
+        let c = gemm(&b.t(), &a.t());
+        assert_eq!(c.data, naive(&b.transpose(), &a.transpose()).data);
+
+        let c = gemm_strassen(&a.view(..6, ..8), &b.view(..8, ..), 2);
+        assert_eq!(c.data, gemm(&a.view(..6, ..8), &b.view(..8, ..)).data);
+
+        let y = gemv(&a.col(3).t(), &Vector::from_gen(7, |i| i as f64));
+        assert_eq!(
+            y[0],
+            (0..7).map(|i| (9.0 * i as f64 - 27.0) * i as f64).sum()
+        );
+    }
+
+    #[test]
+    fn gemm_layouts() {
+        let a = Matrix::from_gen(5, 7, |i, j| (i * 7 + j) as f64 - 17.0);
+        let b = Matrix::from_gen(7, 3, |i, j| ((i + 4 * j) % 6) as f64);
+        let expected = gemm(&a, &b);
+
+        let (a_cm, b_cm) = (a.to_col_major(), b.to_col_major());
+        assert_eq!(gemm(&a_cm, &b_cm).data, expected.data);
+        assert_eq!(gemm(&a, &b_cm).data, expected.data);
+
+        let y = gemv(&a_cm, &Vector::from_gen(7, |j| j as f64));
+        let x = gemv(&a, &Vector::from_gen(7, |j| j as f64));
+        assert_eq!(y.data, x.data);
+
+        // B^T * A^T computed from O(1) transposes into column-major storage
+        let (at, bt) = (
+            a.to_col_major().into_transpose(),
+            b.to_col_major().into_transpose(),
+        );
+        assert_eq!(at.layout(), Layout::RowMajor);
+        let mut c = Matrix::<f64>::zeros(5, 3).into_transpose();
+        gemm_into(1.0, &bt, Op::Identity, &at, Op::Identity, 0.0, &mut c);
+        assert_eq!(c.layout(), Layout::ColMajor);
+        assert_eq!(c.to_row_major().data, expected.transpose().data);
+    }
+
+    #[test]
+    fn gemm_into_views() {
+        let a = Matrix::from_gen(4, 4, |i, j| (i + j) as f64);
+        let mut c = Matrix::<f64>::zeros(4, 4);
+
+        // Write the product of the top-left block into the bottom-right block of c
+        let block = a.view(..2, ..2);
+        gemm_into(
+            1.0,
+            &block,
+            Op::Identity,
+            &block,
+            Op::Identity,
+            0.0,
+            &mut c.view_mut(2.., 2..),
+        );
+        assert_eq!(c.data[..10], [0.0; 10]);
+        assert_eq!(c[(2, 2)], 1.0);
+        assert_eq!(c[(3, 3)], 5.0);
+
+        let (top, bottom) = c.split_at_row_mut(2);
+        gemm_into(
+            1.0,
+            &bottom,
+            Op::Transpose,
+            &block,
+            Op::Identity,
+            0.0,
+            &mut top.t_mut(),
+        );
+        assert_eq!(c[(0, 2)], 2.0);
+        assert_eq!(c[(1, 3)], 12.0);
+    }
+
+    #[test]
+    fn gemv_into_ops() {
+        let a = Matrix::from_gen(2, 3, |i, j| (i * 3 + j) as f64);
+        let x = Vector::from_vec(&[1.0, -1.0, 2.0]);
+        let mut y = Vector::from_vec(&[1.0, 1.0]);
+
+        gemv_into(2.0, &a, Op::Identity, &x, 3.0, &mut y);
+        assert_eq!(y[0], 2.0 * 3.0 + 3.0);
+        assert_eq!(y[1], 2.0 * 9.0 + 3.0);
+
+        let x = Vector::from_vec(&[1.0, 2.0]);
+        let mut y = Vector::from_vec(&[f64::NAN; 3]);
+        gemv_into(1.0, &a, Op::Transpose, &x, 0.0, &mut y);
+        assert_eq!(y[0], 6.0);
+        assert_eq!(y[1], 9.0);
+        assert_eq!(y[2], 12.0);
+    }
+
+    #[test]
+    fn dot_axpy() {
+        let x = Vector::from_gen(19, |i| i as f64);
+        let mut y = Vector::from_gen(19, |i| 1.0 - i as f64);
+        assert_eq!(dot(&x, &y), (0..19).map(|i| (i * (1 - i)) as f64).sum());
+
+        axpy(2.0, &x, &mut y);
+        for i in 0..19 {
+            assert_eq!(y[i], 1.0 + i as f64);
+        }
+
+        let x = Vector::from_gen(5, |i| i as i32);
+        let mut y = Vector::from_gen(5, |_| 1);
+        axpy(3, &x, &mut y);
+        assert_eq!(dot(&x, &y), 100);
+    }
+
+    #[test]
+    fn try_products() {
+        let a = Matrix::from_gen(2, 3, |i, j| (i + j) as f64);
+        let x = Vector::from([1.0, 0.0, -1.0]);
+
+        assert_eq!(
+            try_gemm(&a, &a.t()).unwrap().as_slice(),
+            &[5.0, 8.0, 8.0, 14.0]
+        );
+        assert_eq!(try_gemv(&a, &x).unwrap().as_slice(), &[-2.0, -2.0]);
+        assert_eq!(try_dot(&x, &x), Ok(2.0));
+
+        assert_eq!(
+            try_gemm(&a, &a).unwrap_err(),
+            DimensionMismatchError {
+                lhs: (2, 3),
+                rhs: (2, 3)
+            }
+        );
+        assert_eq!(
+            try_gemv(&a.t(), &x).unwrap_err(),
+            DimensionMismatchError {
+                lhs: (3, 2),
+                rhs: (3, 1)
+            }
+        );
+        assert!(try_dot(&x, &Vector::from([1.0])).is_err());
+    }
+
+    #[test]
+    #[should_panic]
+    fn axpy_dims() {
+        let x = Vector::<f32>::from_vec(&[1.0, 2.0]);
+        let mut y = Vector::<f32>::from_vec(&[1.0]);
+
+        axpy(1.0, &x, &mut y);
+    }
+
+    #[test]
+    fn strassen_odd_shapes() {
+        let gen: fn(usize, usize) -> f64 = |i, j| ((i * 5 + j * 3) % 7) as f64 - 3.0;
+        for (m, k, n) in [(1, 1, 1), (8, 8, 8), (9, 7, 11), (33, 17, 20), (64, 65, 63)] {
+            let a = Matrix::from_gen(m, k, gen);
+            let b = Matrix::from_gen(k, n, gen);
+
+            let expected = gemm(&a, &b);
+            for crossover in [1, 4, 16] {
+                let c = gemm_strassen(&a, &b, crossover);
+                assert_eq!((c.n_rows, c.n_cols), (m, n));
+                assert_eq!(c.data, expected.data);
+            }
+        }
+    }
+
+    #[test]
+    fn strassen_f32() {
+        let a: Matrix<f32> = Matrix::from_gen(50, 50, |i, j| ((i + 2 * j) % 9) as f32 / 8.0);
+
+        let c = gemm_strassen(&a, &a, 8);
+        let expected = gemm(&a, &a);
+        for (x, y) in c.data.iter().zip(&expected.data) {
+            assert!((x - y).abs() <= 1e-4 * (1.0 + y.abs()));
+        }
+    }
+
+    #[test]
+    fn dot_complex() {
+        use crate::core::complex::Complex;
+
+        let x = Vector::from([Complex::new(1.0, 2.0), Complex::new(0.0, -1.0)]);
+        let y = Vector::from([Complex::new(3.0, 1.0), Complex::new(2.0, 2.0)]);
+
+        assert_eq!(dot(&x, &y), Complex::new(3.0, 5.0));
+        assert_eq!(dotc(&x, &y), Complex::new(3.0, -3.0));
+        assert_eq!(dotc(&x, &x), Complex::new(6.0, 0.0));
+
+        let u = Vector::from([1.0, -2.0, 0.5]);
+        assert_eq!(dotc(&u, &u), dot(&u, &u));
+    }
 }
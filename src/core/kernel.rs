@@ -0,0 +1,197 @@
+use crate::core::parallel::for_each;
+use crate::core::scalar::RealField;
+use crate::core::simd::{microkernel, nr, MR, NR_MAX};
+use crate::core::view::{MatrixView, MatrixViewMut};
+
+/// Depth of the packed panels, sized so a `KC x nr` panel of `B` stays in L1.
+pub(crate) const KC: usize = 256;
+/// Rows of the packed block of `A`, a multiple of `MR` sized so an `MC x KC` block stays in L2.
+pub(crate) const MC: usize = 120;
+/// Columns of the packed block of `B`, sized so a `KC x NC` block stays in L3.
+pub(crate) const NC: usize = 4096;
+
+/// Computes `C = alpha * A * B + beta * C` with the packed, cache-blocked algorithm of Goto and
+/// van de Geijn. As in BLAS, `C` is not read when `beta` is zero.
+///
+/// `B` is packed into `KC x NC` blocks of `nr` column wide panels and `A` into `MC x KC` blocks
+/// of `MR` row tall panels, so the microkernel streams through contiguous memory. Panels at the
+/// edges are padded with zeros and only the valid part of each tile is written back.
+///
+/// Blocks of `MC` rows of `C` are computed independently, in parallel with the `parallel`
+/// feature. Every element is accumulated in the same order either way.
+pub(crate) fn gemm_blocked<T>(
+    alpha: T,
+    a: MatrixView<T>,
+    b: MatrixView<T>,
+    beta: T,
+    c: &mut MatrixViewMut<T>,
+) where
+    T: RealField,
+{
+    let (m, k, n) = (a.n_rows, a.n_cols, b.n_cols);
+    debug_assert!(b.n_rows == k && c.n_rows == m && c.n_cols == n);
+
+    if m <= MC || k == 0 || n == 0 {
+        gemm_serial(alpha, a, b, beta, c);
+        return;
+    }
+
+    let mut blocks = Vec::with_capacity(m.div_ceil(MC));
+    let mut rest = c.reborrow();
+    for row in (0..m).step_by(MC) {
+        let (block, tail) = rest.split_at_row_mut(MC.min(m - row));
+        blocks.push((row, block));
+        rest = tail;
+    }
+    for_each(blocks, |(row, mut block)| {
+        let a_block = a.block(row, 0, block.n_rows, k);
+        gemm_serial(alpha, a_block, b, beta, &mut block);
+    });
+}
+
+fn gemm_serial<T>(alpha: T, a: MatrixView<T>, b: MatrixView<T>, beta: T, c: &mut MatrixViewMut<T>)
+where
+    T: RealField,
+{
+    let (m, k, n) = (a.n_rows, a.n_cols, b.n_cols);
+    debug_assert!(b.n_rows == k && c.n_rows == m && c.n_cols == n);
+
+    if beta != T::one() {
+        for i in 0..m {
+            for j in 0..n {
+                // SAFETY: `i < m` and `j < n`
+                let cij = unsafe { c.at_unchecked_mut(i, j) };
+                *cij = if beta == T::zero() {
+                    T::zero()
+                } else {
+                    beta * *cij
+                };
+            }
+        }
+    }
+    if m == 0 || n == 0 || k == 0 || alpha == T::zero() {
+        return;
+    }
+
+    let (nr_full, kernel) = (nr::<T>(), microkernel::<T>());
+    let mut a_pack = vec![T::zero(); MC.min(m).div_ceil(MR) * MR * KC.min(k)];
+    let mut b_pack = vec![T::zero(); NC.min(n).div_ceil(nr_full) * nr_full * KC.min(k)];
+    let mut ab = [T::zero(); MR * NR_MAX];
+
+    for jc in (0..n).step_by(NC) {
+        let nc = NC.min(n - jc);
+        for pc in (0..k).step_by(KC) {
+            let kc = KC.min(k - pc);
+            pack_b(&b, pc, jc, kc, nc, &mut b_pack);
+
+            for ic in (0..m).step_by(MC) {
+                let mc = MC.min(m - ic);
+                pack_a(&a, ic, pc, mc, kc, &mut a_pack);
+
+                for jr in (0..nc).step_by(nr_full) {
+                    let nr = nr_full.min(nc - jr);
+                    let b_panel = &b_pack[jr * kc..(jr + nr_full) * kc];
+                    for ir in (0..mc).step_by(MR) {
+                        let mr = MR.min(mc - ir);
+                        let a_panel = &a_pack[ir * kc..(ir + MR) * kc];
+
+                        kernel(kc, a_panel, b_panel, &mut ab);
+                        for i in 0..mr {
+                            for j in 0..nr {
+                                // SAFETY: the tile lies within the `mc x nc` block of C
+                                let cij = unsafe { c.at_unchecked_mut(ic + ir + i, jc + jr + j) };
+                                *cij += alpha * ab[i * nr_full + j];
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Packs `A[ic..ic + mc, pc..pc + kc]` into `MR` row panels, each stored column by column.
+fn pack_a<T>(a: &MatrixView<T>, ic: usize, pc: usize, mc: usize, kc: usize, pack: &mut [T])
+where
+    T: RealField,
+{
+    assert!(ic + mc <= a.n_rows && pc + kc <= a.n_cols);
+    for (panel, ir) in (0..mc).step_by(MR).enumerate() {
+        let mr = MR.min(mc - ir);
+        let dst = &mut pack[panel * MR * kc..(panel + 1) * MR * kc];
+        for p in 0..kc {
+            for i in 0..MR {
+                dst[p * MR + i] = if i < mr {
+                    // SAFETY: the block is within the shape of `a`, checked above
+                    unsafe { a.at_unchecked(ic + ir + i, pc + p) }
+                } else {
+                    T::zero()
+                };
+            }
+        }
+    }
+}
+
+/// Packs `B[pc..pc + kc, jc..jc + nc]` into `nr` column panels, each stored row by row.
+fn pack_b<T>(b: &MatrixView<T>, pc: usize, jc: usize, kc: usize, nc: usize, pack: &mut [T])
+where
+    T: RealField,
+{
+    assert!(pc + kc <= b.n_rows && jc + nc <= b.n_cols);
+    let nr_full = nr::<T>();
+    for (panel, jr) in (0..nc).step_by(nr_full).enumerate() {
+        let nr = nr_full.min(nc - jr);
+        let dst = &mut pack[panel * nr_full * kc..(panel + 1) * nr_full * kc];
+        for p in 0..kc {
+            for j in 0..nr_full {
+                dst[p * nr_full + j] = if j < nr {
+                    // SAFETY: the block is within the shape of `b`, checked above
+                    unsafe { b.at_unchecked(pc + p, jc + jr + j) }
+                } else {
+                    T::zero()
+                };
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_row_blocks_match_serial() {
+        let (m, k, n) = (3 * MC + 17, 70, 45);
+        let a: Vec<f64> = (0..m * k).map(|x| ((x * 37) % 101) as f64 / 7.0).collect();
+        let b: Vec<f64> = (0..k * n)
+            .map(|x| ((x * 13) % 29) as f64 / 3.0 - 4.0)
+            .collect();
+        let c0: Vec<f64> = (0..m * n).map(|x| (x % 5) as f64).collect();
+
+        // A is read through its transpose to exercise a non-unit row stride
+        let at: Vec<f64> = (0..k * m).map(|x| a[(x % m) * k + x / m]).collect();
+        for a in [
+            MatrixView::row_major(&a, m, k),
+            MatrixView::row_major(&at, k, m).t(),
+        ] {
+            let b = MatrixView::row_major(&b, k, n);
+            let mut blocked = c0.clone();
+            let mut serial = c0.clone();
+            gemm_blocked(
+                1.5,
+                a,
+                b,
+                0.5,
+                &mut MatrixViewMut::row_major(&mut blocked, m, n),
+            );
+            gemm_serial(
+                1.5,
+                a,
+                b,
+                0.5,
+                &mut MatrixViewMut::row_major(&mut serial, m, n),
+            );
+            assert_eq!(blocked, serial);
+        }
+    }
+}
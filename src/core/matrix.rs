@@ -1,53 +1,359 @@
 use std::default::Default;
-use std::ops::{Add, AddAssign, Index, IndexMut, Sub, SubAssign};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
-#[derive(Debug)]
+use crate::core::error::ShapeError;
+use crate::core::error::ShapeError::{DimensionMismatchError, LengthMismatchError, RaggedError};
+use crate::core::gemm::{gemm, gemv};
+use crate::core::parallel::{for_each_row_block, rows_per_block, zip_map, MaybeSync};
+use crate::core::scalar::{ComplexField, One, Zero};
+use crate::core::vector::{norm2, Vector};
+
+/// Order in which the elements of a [`Matrix`] are laid out in its storage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Layout {
+    /// Rows are contiguous: element `(i, j)` is stored at `i * n_cols + j`.
+    #[default]
+    RowMajor,
+    /// Columns are contiguous: element `(i, j)` is stored at `i + j * n_rows`, as in Fortran
+    /// and LAPACK.
+    ColMajor,
+    /// Element `(i, j)` is stored at `i * row_stride + j * col_stride`, e.g. for storage with a
+    /// padded leading dimension.
+    Strided {
+        row_stride: usize,
+        col_stride: usize,
+    },
+}
+
+impl Layout {
+    /// Row and column strides of an `n_rows x n_cols` matrix stored with this layout.
+    pub fn strides(self, n_rows: usize, n_cols: usize) -> (usize, usize) {
+        match self {
+            Layout::RowMajor => (n_cols, 1),
+            Layout::ColMajor => (1, n_rows),
+            Layout::Strided {
+                row_stride,
+                col_stride,
+            } => (row_stride, col_stride),
+        }
+    }
+
+    /// Whether an `n_rows x n_cols` matrix with this layout fits in `len` elements of storage
+    /// without any two elements sharing a location.
+    fn fits(self, n_rows: usize, n_cols: usize, len: usize) -> bool {
+        if n_rows == 0 || n_cols == 0 {
+            return true;
+        }
+
+        let (row_stride, col_stride) = self.strides(n_rows, n_cols);
+        let (inner, outer) = if row_stride <= col_stride {
+            ((n_rows, row_stride), (n_cols, col_stride))
+        } else {
+            ((n_cols, col_stride), (n_rows, row_stride))
+        };
+        // The inner dimension must fit between consecutive entries of the outer one
+        let distinct = (inner.0 == 1 || inner.1 >= 1)
+            && (outer.0 == 1 || outer.1 >= (inner.0 * inner.1).max(1));
+
+        distinct && (n_rows - 1) * row_stride + (n_cols - 1) * col_stride < len
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct Matrix<T> {
     pub n_rows: usize,
     pub n_cols: usize,
     pub(crate) data: Vec<T>,
+    pub(crate) layout: Layout,
+}
+
+impl<T> Matrix<T> {
+    /// Matrix whose entry `(i, j)` is `f(i, j)`, evaluated row by row. Unlike `from_gen`, `f`
+    /// may capture its environment.
+    pub fn from_fn<F>(n_rows: usize, n_cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut data = Vec::with_capacity(n_rows * n_cols);
+        for i in 0..n_rows {
+            for j in 0..n_cols {
+                data.push(f(i, j));
+            }
+        }
+
+        Self {
+            n_rows,
+            n_cols,
+            data,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    /// Wraps the row-major `data` of an `n_rows x n_cols` matrix without copying.
+    pub fn from_vec(n_rows: usize, n_cols: usize, data: Vec<T>) -> Result<Self, ShapeError> {
+        if data.len() != n_rows * n_cols {
+            return Err(LengthMismatchError {
+                expected: n_rows * n_cols,
+                got: data.len(),
+            });
+        }
+
+        Ok(Self {
+            n_rows,
+            n_cols,
+            data,
+            layout: Layout::RowMajor,
+        })
+    }
+
+    /// Storage of the matrix, in the order given by its layout.
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// Storage of the matrix, in the order given by its layout.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Storage of the matrix, in the order given by its layout.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Wraps `data` holding an `n_rows x n_cols` matrix stored with the given layout. Panics if
+    /// `data` is too short, or if the strides would map two elements to the same location.
+    pub fn with_layout(n_rows: usize, n_cols: usize, data: Vec<T>, layout: Layout) -> Self {
+        if !layout.fits(n_rows, n_cols, data.len()) {
+            panic!(
+                "Layout {:?} is invalid for a matrix of size {:?} with {} elements of storage",
+                layout,
+                (n_rows, n_cols),
+                data.len()
+            );
+        }
+
+        Self {
+            n_rows,
+            n_cols,
+            data,
+            layout,
+        }
+    }
+
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Row and column strides of the storage.
+    pub fn strides(&self) -> (usize, usize) {
+        self.layout.strides(self.n_rows, self.n_cols)
+    }
+
+    #[inline(always)]
+    fn offset(&self, i: usize, j: usize) -> usize {
+        let (row_stride, col_stride) = self.strides();
+        i * row_stride + j * col_stride
+    }
+
+    /// Transpose in O(1), by swapping the shape and reinterpreting the storage: a row-major
+    /// matrix becomes column-major and vice versa.
+    pub fn into_transpose(self) -> Self {
+        let layout = match self.layout {
+            Layout::RowMajor => Layout::ColMajor,
+            Layout::ColMajor => Layout::RowMajor,
+            Layout::Strided {
+                row_stride,
+                col_stride,
+            } => Layout::Strided {
+                row_stride: col_stride,
+                col_stride: row_stride,
+            },
+        };
+
+        Self {
+            n_rows: self.n_cols,
+            n_cols: self.n_rows,
+            data: self.data,
+            layout,
+        }
+    }
+
+    /// Whether every location of the storage holds an element, with no padding between them.
+    fn is_dense(&self) -> bool {
+        self.data.len() == self.n_rows * self.n_cols
+    }
+
+    /// Whether `self` and `other` are dense and store corresponding elements at the same
+    /// locations, so they can be combined element by element through their storage.
+    fn same_storage_as(&self, other: &Self) -> bool {
+        self.is_dense() && other.is_dense() && self.strides() == other.strides()
+    }
 }
 
 impl<T> Matrix<T>
 where
-    T: Copy + Clone + Default,
+    T: Clone,
 {
-    pub fn new(n_rows: usize, n_cols: usize) -> Self {
-        let data = vec![Default::default(); n_rows * n_cols];
+    /// Matrix with the given rows, which must all have the same length.
+    pub fn from_rows<R>(rows: &[R]) -> Result<Self, ShapeError>
+    where
+        R: AsRef<[T]>,
+    {
+        let (n_rows, n_cols, data) = concat(rows)?;
+
+        Ok(Self {
+            n_rows,
+            n_cols,
+            data,
+            layout: Layout::RowMajor,
+        })
+    }
+
+    /// Matrix with the given columns, which must all have the same length. The result is stored
+    /// column-major.
+    pub fn from_cols<C>(cols: &[C]) -> Result<Self, ShapeError>
+    where
+        C: AsRef<[T]>,
+    {
+        let (n_cols, n_rows, data) = concat(cols)?;
+
+        Ok(Self {
+            n_rows,
+            n_cols,
+            data,
+            layout: Layout::ColMajor,
+        })
+    }
+
+    /// Copy of the matrix stored in `layout`, which must be row-major or column-major.
+    pub fn to_layout(&self, layout: Layout) -> Self {
+        let (n_rows, n_cols) = (self.n_rows, self.n_cols);
+        let data = match layout {
+            Layout::RowMajor => (0..n_rows * n_cols)
+                .map(|x| self.data[self.offset(x / n_cols, x % n_cols)].clone())
+                .collect(),
+            Layout::ColMajor => (0..n_rows * n_cols)
+                .map(|x| self.data[self.offset(x % n_rows, x / n_rows)].clone())
+                .collect(),
+            Layout::Strided { .. } => panic!("Cannot convert a matrix to a strided layout"),
+        };
+
         Self {
             n_rows,
             n_cols,
             data,
+            layout,
         }
     }
 
-    pub fn from_gen(n_rows: usize, n_cols: usize, gen: fn(usize, usize) -> T) -> Self {
-        let mut data = Vec::with_capacity(n_rows * n_cols);
-        (0..n_rows * n_cols)
-            .map(|i| (i / n_cols, i % n_cols))
-            .for_each(|(i, j)| {
-                data.push(gen(i, j));
-            });
+    /// Copy of the matrix stored row-major, the layout expected by most of this crate.
+    pub fn to_row_major(&self) -> Self {
+        self.to_layout(Layout::RowMajor)
+    }
 
+    /// Copy of the matrix stored column-major, the layout expected by LAPACK.
+    pub fn to_col_major(&self) -> Self {
+        self.to_layout(Layout::ColMajor)
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Clone + Default,
+{
+    pub fn new(n_rows: usize, n_cols: usize) -> Self {
+        let data = vec![Default::default(); n_rows * n_cols];
         Self {
             n_rows,
             n_cols,
             data,
+            layout: Layout::RowMajor,
         }
     }
 
-    pub fn transpose(&self) -> Self {
-        let mut data = Vec::with_capacity(self.n_cols * self.n_rows);
-        for j in 0..self.n_cols {
-            for i in 0..self.n_rows {
-                data.push(self.data[i * self.n_cols + j]);
+    pub fn from_gen(n_rows: usize, n_cols: usize, gen: fn(usize, usize) -> T) -> Self {
+        Self::from_fn(n_rows, n_cols, gen)
+    }
+}
+
+impl<T> Matrix<T> {
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        if i >= self.n_rows || j >= self.n_rows {
+            panic!(
+                "Row index {:?} out of bounds for matrix with {} rows",
+                (i, j),
+                self.n_rows
+            );
+        }
+        if i == j {
+            return;
+        }
+
+        if self.layout != Layout::RowMajor {
+            for k in 0..self.n_cols {
+                let (a, b) = (self.offset(i, k), self.offset(j, k));
+                self.data.swap(a, b);
             }
+            return;
         }
 
+        let (lo, hi) = (i.min(j), i.max(j));
+        let (head, tail) = self.data.split_at_mut(hi * self.n_cols);
+        head[lo * self.n_cols..(lo + 1) * self.n_cols].swap_with_slice(&mut tail[..self.n_cols]);
+    }
+
+    pub fn swap_cols(&mut self, i: usize, j: usize) {
+        if i >= self.n_cols || j >= self.n_cols {
+            panic!(
+                "Column index {:?} out of bounds for matrix with {} columns",
+                (i, j),
+                self.n_cols
+            );
+        }
+        if i == j {
+            return;
+        }
+
+        if self.layout != Layout::RowMajor {
+            for k in 0..self.n_rows {
+                let (a, b) = (self.offset(k, i), self.offset(k, j));
+                self.data.swap(a, b);
+            }
+            return;
+        }
+
+        for row in self.data.chunks_mut(self.n_cols) {
+            row.swap(i, j);
+        }
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Copy + Clone + Default + MaybeSync,
+{
+    /// Copy of the transpose, stored row-major. `t` gives a view and `into_transpose` a
+    /// reinterpretation of the storage instead, both in O(1).
+    pub fn transpose(&self) -> Self {
+        let (n_rows, n_cols) = (self.n_rows, self.n_cols);
+        let (row_stride, col_stride) = self.strides();
+        let mut data = vec![T::default(); n_rows * n_cols];
+        for_each_row_block(&mut data, n_rows, rows_per_block(n_rows), |row, block| {
+            for (j, t_row) in block.chunks_exact_mut(n_rows).enumerate() {
+                for (i, t) in t_row.iter_mut().enumerate() {
+                    *t = self.data[i * row_stride + (row + j) * col_stride];
+                }
+            }
+        });
+
         Self {
-            n_rows: self.n_cols,
-            n_cols: self.n_rows,
+            n_rows: n_cols,
+            n_cols: n_rows,
             data,
+            layout: Layout::RowMajor,
         }
     }
 }
@@ -72,6 +378,165 @@ where
     }
 }
 
+impl<T> Matrix<T>
+where
+    T: ComplexField,
+{
+    /// Conjugate transpose, which is the transpose for real matrices.
+    pub fn adjoint(&self) -> Self {
+        Self::from_fn(self.n_cols, self.n_rows, |i, j| self[(j, i)].conj())
+    }
+
+    /// Whether the matrix equals its conjugate transpose, which for real matrices is whether it
+    /// is symmetric.
+    pub fn is_hermitian(&self) -> bool {
+        if self.n_rows != self.n_cols {
+            return false;
+        }
+        for i in 0..self.n_rows {
+            for j in i..self.n_rows {
+                if self[(i, j)] != self[(j, i)].conj() {
+                    return false;
+                }
+            }
+        }
+
+        true
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: ComplexField,
+{
+    /// Frobenius norm, the Euclidean norm of all the entries.
+    pub fn norm_fro(&self) -> T::Real {
+        norm2((0..self.n_rows).flat_map(|i| (0..self.n_cols).map(move |j| &self[(i, j)])))
+    }
+
+    /// Norm induced by the vector 1-norm, the largest sum of absolute values in a column.
+    pub fn norm1(&self) -> T::Real {
+        (0..self.n_cols)
+            .map(|j| (0..self.n_rows).map(|i| self[(i, j)].modulus()).sum())
+            .fold(T::Real::zero(), max)
+    }
+
+    /// Norm induced by the vector infinity-norm, the largest sum of absolute values in a row.
+    pub fn norm_inf(&self) -> T::Real {
+        (0..self.n_rows)
+            .map(|i| (0..self.n_cols).map(|j| self[(i, j)].modulus()).sum())
+            .fold(T::Real::zero(), max)
+    }
+
+    /// Largest absolute value of the entries. Not submultiplicative, so not a matrix norm in
+    /// the strict sense.
+    pub fn norm_max(&self) -> T::Real {
+        (0..self.n_rows)
+            .flat_map(|i| (0..self.n_cols).map(move |j| self[(i, j)].modulus()))
+            .fold(T::Real::zero(), max)
+    }
+}
+
+fn max<T: PartialOrd>(x: T, y: T) -> T {
+    if y > x {
+        y
+    } else {
+        x
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Zero + AddAssign + Clone,
+{
+    /// Sum of the diagonal entries of a square matrix.
+    pub fn trace(&self) -> T {
+        if self.n_rows != self.n_cols {
+            panic!(
+                "Trace of non-square matrix of shape {:?}",
+                (self.n_rows, self.n_cols)
+            );
+        }
+
+        let mut trace = T::zero();
+        for i in 0..self.n_rows {
+            trace += self[(i, i)].clone();
+        }
+
+        trace
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Clone,
+{
+    /// Entries `(i, i)` of the main diagonal, as many as the smaller dimension.
+    pub fn diag(&self) -> Vector<T> {
+        Vector::from_fn(self.n_rows.min(self.n_cols), |i| self[(i, i)].clone())
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Zero + Clone,
+{
+    /// Square matrix with `diag` on its main diagonal and zeros elsewhere.
+    pub fn from_diag(diag: &Vector<T>) -> Self {
+        Self::from_fn(diag.n, diag.n, |i, j| {
+            if i == j {
+                diag[i].clone()
+            } else {
+                T::zero()
+            }
+        })
+    }
+}
+
+/// Concatenates `parts` of equal length, returning their number, their length and the data.
+fn concat<T, R>(parts: &[R]) -> Result<(usize, usize, Vec<T>), ShapeError>
+where
+    T: Clone,
+    R: AsRef<[T]>,
+{
+    let len = parts.first().map_or(0, |p| p.as_ref().len());
+    let mut data = Vec::with_capacity(parts.len() * len);
+    for part in parts {
+        let part = part.as_ref();
+        if part.len() != len {
+            return Err(RaggedError {
+                expected: len,
+                got: part.len(),
+            });
+        }
+        data.extend_from_slice(part);
+    }
+
+    Ok((parts.len(), len, data))
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for Matrix<T> {
+    fn from(rows: [[T; C]; R]) -> Self {
+        Self {
+            n_rows: R,
+            n_cols: C,
+            data: rows.into_iter().flatten().collect(),
+            layout: Layout::RowMajor,
+        }
+    }
+}
+
+impl<T> TryFrom<Vec<Vec<T>>> for Matrix<T>
+where
+    T: Clone,
+{
+    type Error = ShapeError;
+
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        Self::from_rows(&rows)
+    }
+}
+
 impl<T> Index<(usize, usize)> for Matrix<T> {
     type Output = T;
 
@@ -85,7 +550,7 @@ impl<T> Index<(usize, usize)> for Matrix<T> {
             );
         }
 
-        &self.data[i * self.n_cols + j]
+        &self.data[self.offset(i, j)]
     }
 }
 
@@ -100,13 +565,47 @@ impl<T> IndexMut<(usize, usize)> for Matrix<T> {
             );
         }
 
-        &mut self.data[i * self.n_cols + j]
+        let offset = self.offset(i, j);
+        &mut self.data[offset]
+    }
+}
+
+impl<T> Matrix<T> {
+    fn check_same_shape(&self, other: &Self) -> Result<(), ShapeError> {
+        if self.n_rows != other.n_rows || self.n_cols != other.n_cols {
+            return Err(DimensionMismatchError {
+                lhs: (self.n_rows, self.n_cols),
+                rhs: (other.n_rows, other.n_cols),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Computes `self + other`, or fails if the shapes differ.
+    pub fn try_add(&self, other: &Self) -> Result<Self, ShapeError>
+    where
+        T: Add<Output = T> + Clone + MaybeSync,
+    {
+        self.check_same_shape(other)?;
+
+        Ok(self + other)
+    }
+
+    /// Computes `self - other`, or fails if the shapes differ.
+    pub fn try_sub(&self, other: &Self) -> Result<Self, ShapeError>
+    where
+        T: Sub<Output = T> + Clone + MaybeSync,
+    {
+        self.check_same_shape(other)?;
+
+        Ok(self - other)
     }
 }
 
 impl<T> Add for &Matrix<T>
 where
-    T: Add<Output = T> + Clone,
+    T: Add<Output = T> + Clone + MaybeSync,
 {
     type Output = Matrix<T>;
 
@@ -118,17 +617,17 @@ where
             );
         }
 
-        let data = self
-            .data
-            .iter()
-            .zip(other.data.iter())
-            .map(|(x, y)| x.clone() + y.clone())
-            .collect();
+        if !self.same_storage_as(other) {
+            return &self.to_row_major() + &other.to_row_major();
+        }
+
+        let data = zip_map(&self.data, &other.data, |x, y| x.clone() + y.clone());
 
         Self::Output {
             n_rows: self.n_rows,
             n_cols: self.n_cols,
             data,
+            layout: self.layout,
         }
     }
 }
@@ -145,6 +644,15 @@ where
             );
         }
 
+        if !self.same_storage_as(other) {
+            for i in 0..self.n_rows {
+                for j in 0..self.n_cols {
+                    self[(i, j)] += other[(i, j)].clone();
+                }
+            }
+            return;
+        }
+
         self.data
             .iter_mut()
             .zip(other.data.iter())
@@ -154,7 +662,7 @@ where
 
 impl<T> Sub for &Matrix<T>
 where
-    T: Sub<Output = T> + Clone,
+    T: Sub<Output = T> + Clone + MaybeSync,
 {
     type Output = Matrix<T>;
 
@@ -166,17 +674,17 @@ where
             );
         }
 
-        let data = self
-            .data
-            .iter()
-            .zip(other.data.iter())
-            .map(|(x, y)| x.clone() - y.clone())
-            .collect();
+        if !self.same_storage_as(other) {
+            return &self.to_row_major() - &other.to_row_major();
+        }
+
+        let data = zip_map(&self.data, &other.data, |x, y| x.clone() - y.clone());
 
         Self::Output {
             n_rows: self.n_rows,
             n_cols: self.n_cols,
             data,
+            layout: self.layout,
         }
     }
 }
@@ -193,6 +701,15 @@ where
             );
         }
 
+        if !self.same_storage_as(other) {
+            for i in 0..self.n_rows {
+                for j in 0..self.n_cols {
+                    self[(i, j)] -= other[(i, j)].clone();
+                }
+            }
+            return;
+        }
+
         self.data
             .iter_mut()
             .zip(other.data.iter())
@@ -200,71 +717,336 @@ where
     }
 }
 
-macro_rules! matrix_zeros {
-    ($type:ty) => {
-        impl Matrix<$type> {
-            pub fn zeros(n_rows: usize, n_cols: usize) -> Self {
-                let data = (0..n_cols * n_rows).map(|_| 0 as $type).collect();
-
-                Self {
-                    n_rows,
-                    n_cols,
-                    data,
-                }
+/// Implements the owned and mixed owned/borrowed variants of a binary operator in terms of the
+/// one between two references.
+macro_rules! forward_ref_binop {
+    (impl $imp:ident, $method:ident for $lhs:ty, $rhs:ty => $out:ty) => {
+        impl<T> $imp<$rhs> for $lhs
+        where
+            for<'a> &'a $lhs: $imp<&'a $rhs, Output = $out>,
+        {
+            type Output = $out;
+
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<T> $imp<&$rhs> for $lhs
+        where
+            for<'a> &'a $lhs: $imp<&'a $rhs, Output = $out>,
+        {
+            type Output = $out;
+
+            fn $method(self, rhs: &$rhs) -> Self::Output {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<T> $imp<$rhs> for &$lhs
+        where
+            for<'a> &'a $lhs: $imp<&'a $rhs, Output = $out>,
+        {
+            type Output = $out;
+
+            fn $method(self, rhs: $rhs) -> Self::Output {
+                self.$method(&rhs)
             }
         }
     };
 }
 
-macro_rules! matrix_ones {
-    ($type:ty) => {
-        impl Matrix<$type> {
-            pub fn ones(n_rows: usize, n_cols: usize) -> Self {
-                let data = (0..n_cols * n_rows).map(|_| 1 as $type).collect();
-
-                Self {
-                    n_rows,
-                    n_cols,
-                    data,
+pub(crate) use forward_ref_binop;
+
+forward_ref_binop!(impl Add, add for Matrix<T>, Matrix<T> => Matrix<T>);
+forward_ref_binop!(impl Sub, sub for Matrix<T>, Matrix<T> => Matrix<T>);
+forward_ref_binop!(impl Mul, mul for Matrix<T>, Matrix<T> => Matrix<T>);
+forward_ref_binop!(impl Mul, mul for Matrix<T>, Vector<T> => Vector<T>);
+
+impl<T> Mul for &Matrix<T>
+where
+    T: Add<Output = T> + AddAssign + Mul<Output = T> + Copy + Default + MaybeSync + 'static,
+{
+    type Output = Matrix<T>;
+
+    /// Matrix product, see `gemm`.
+    fn mul(self, rhs: Self) -> Self::Output {
+        gemm(self, rhs)
+    }
+}
+
+impl<T> Mul<&Vector<T>> for &Matrix<T>
+where
+    T: Add<Output = T> + AddAssign + Mul<Output = T> + Copy + Default + MaybeSync,
+{
+    type Output = Vector<T>;
+
+    /// Matrix-vector product, see `gemv`.
+    fn mul(self, rhs: &Vector<T>) -> Self::Output {
+        gemv(self, rhs)
+    }
+}
+
+impl<T> Matrix<T> {
+    /// Applies `f` to every element, keeping the layout unless the storage has padding, in which
+    /// case the result is row-major.
+    pub(crate) fn map<U, F>(&self, mut f: F) -> Matrix<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        if !self.is_dense() {
+            return Matrix::from_fn(self.n_rows, self.n_cols, |i, j| f(&self[(i, j)]));
+        }
+
+        Matrix {
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+            data: self.data.iter().map(f).collect(),
+            layout: self.layout,
+        }
+    }
+
+    /// Applies `f` in place to every element, leaving any padding untouched.
+    fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T),
+    {
+        if !self.is_dense() {
+            for i in 0..self.n_rows {
+                for j in 0..self.n_cols {
+                    f(&mut self[(i, j)]);
                 }
             }
+            return;
         }
-    };
+
+        self.data.iter_mut().for_each(f);
+    }
+
+    /// Combines corresponding elements of two matrices of the same shape with `f`.
+    fn zip_with<F>(&self, other: &Self, op: &str, f: F) -> Self
+    where
+        T: Clone + MaybeSync,
+        F: Fn(&T, &T) -> T + MaybeSync,
+    {
+        if self.n_rows != other.n_rows || self.n_cols != other.n_cols {
+            panic!(
+                "Cannot {} matrices of different shapes: ({}, {}) ({}, {})",
+                op, self.n_rows, self.n_cols, other.n_rows, other.n_cols
+            );
+        }
+        if !self.same_storage_as(other) {
+            return self.to_row_major().zip_with(&other.to_row_major(), op, f);
+        }
+
+        Self {
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+            data: zip_map(&self.data, &other.data, f),
+            layout: self.layout,
+        }
+    }
+
+    /// Element-wise (Hadamard) product.
+    pub fn hadamard(&self, other: &Self) -> Self
+    where
+        T: Mul<Output = T> + Clone + MaybeSync,
+    {
+        self.zip_with(other, "multiply", |x, y| x.clone() * y.clone())
+    }
+
+    /// Element-wise division.
+    pub fn hadamard_div(&self, other: &Self) -> Self
+    where
+        T: Div<Output = T> + Clone + MaybeSync,
+    {
+        self.zip_with(other, "divide", |x, y| x.clone() / y.clone())
+    }
 }
 
-macro_rules! matrix_eye {
+impl<T> Mul<T> for &Matrix<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        self.map(|&x| x * rhs)
+    }
+}
+
+impl<T> Mul<T> for Matrix<T>
+where
+    T: MulAssign + Copy,
+{
+    type Output = Matrix<T>;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<T> MulAssign<T> for Matrix<T>
+where
+    T: MulAssign + Copy,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.for_each_mut(|x| *x *= rhs);
+    }
+}
+
+impl<T> Div<T> for &Matrix<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Matrix<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        self.map(|&x| x / rhs)
+    }
+}
+
+impl<T> Div<T> for Matrix<T>
+where
+    T: DivAssign + Copy,
+{
+    type Output = Matrix<T>;
+
+    fn div(mut self, rhs: T) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+impl<T> DivAssign<T> for Matrix<T>
+where
+    T: DivAssign + Copy,
+{
+    fn div_assign(&mut self, rhs: T) {
+        self.for_each_mut(|x| *x /= rhs);
+    }
+}
+
+impl<T> Neg for &Matrix<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Matrix<T>;
+
+    fn neg(self) -> Self::Output {
+        self.map(|&x| -x)
+    }
+}
+
+impl<T> Neg for Matrix<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Matrix<T>;
+
+    fn neg(mut self) -> Self::Output {
+        self.for_each_mut(|x| *x = -*x);
+        self
+    }
+}
+
+impl<T> PartialEq for Matrix<T>
+where
+    T: PartialEq,
+{
+    /// Matrices are equal if they have the same shape and elements, whatever their layouts.
+    fn eq(&self, other: &Self) -> bool {
+        if self.n_rows != other.n_rows || self.n_cols != other.n_cols {
+            return false;
+        }
+        if self.layout == other.layout && self.is_dense() && other.is_dense() {
+            return self.data == other.data;
+        }
+
+        (0..self.n_rows).all(|i| (0..self.n_cols).all(|j| self[(i, j)] == other[(i, j)]))
+    }
+}
+
+/// Implements `scalar * matrix` and `scalar * vector`, which cannot be implemented generically
+/// as the scalar is the left operand.
+macro_rules! impl_scalar_mul {
     ($type:ty) => {
-        impl Matrix<$type> {
-            pub fn eye(n: usize) -> Self {
-                let mut data = Vec::with_capacity(n * n);
-                (0..n * n).map(|i| (i / n, i % n)).for_each(|(i, j)| {
-                    data.push(if i == j { 1 as $type } else { 0 as $type });
-                });
-
-                Self {
-                    n_rows: n,
-                    n_cols: n,
-                    data,
-                }
+        impl Mul<&Matrix<$type>> for $type {
+            type Output = Matrix<$type>;
+
+            fn mul(self, rhs: &Matrix<$type>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl Mul<Matrix<$type>> for $type {
+            type Output = Matrix<$type>;
+
+            fn mul(self, rhs: Matrix<$type>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl Mul<&Vector<$type>> for $type {
+            type Output = Vector<$type>;
+
+            fn mul(self, rhs: &Vector<$type>) -> Self::Output {
+                rhs * self
+            }
+        }
+
+        impl Mul<Vector<$type>> for $type {
+            type Output = Vector<$type>;
+
+            fn mul(self, rhs: Vector<$type>) -> Self::Output {
+                rhs * self
             }
         }
     };
 }
 
-matrix_zeros!(f32);
-matrix_zeros!(f64);
-matrix_zeros!(i32);
-matrix_zeros!(i64);
+impl_scalar_mul!(i8);
+impl_scalar_mul!(i16);
+impl_scalar_mul!(i32);
+impl_scalar_mul!(i64);
+impl_scalar_mul!(i128);
+impl_scalar_mul!(isize);
+impl_scalar_mul!(u8);
+impl_scalar_mul!(u16);
+impl_scalar_mul!(u32);
+impl_scalar_mul!(u64);
+impl_scalar_mul!(u128);
+impl_scalar_mul!(usize);
+impl_scalar_mul!(f32);
+impl_scalar_mul!(f64);
+
+impl<T> Matrix<T>
+where
+    T: Zero + Clone,
+{
+    pub fn zeros(n_rows: usize, n_cols: usize) -> Self {
+        Self::from_fn(n_rows, n_cols, |_, _| T::zero())
+    }
+}
 
-matrix_ones!(f32);
-matrix_ones!(f64);
-matrix_ones!(i32);
-matrix_ones!(i64);
+impl<T> Matrix<T>
+where
+    T: One + Clone,
+{
+    pub fn ones(n_rows: usize, n_cols: usize) -> Self {
+        Self::from_fn(n_rows, n_cols, |_, _| T::one())
+    }
+}
 
-matrix_eye!(f32);
-matrix_eye!(f64);
-matrix_eye!(i32);
-matrix_eye!(i64);
+impl<T> Matrix<T>
+where
+    T: Zero + One,
+{
+    pub fn eye(n: usize) -> Self {
+        Self::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() })
+    }
+}
 
 #[macro_export]
 macro_rules! matrix {
@@ -289,6 +1071,7 @@ macro_rules! matrix {
                 n_rows,
                 n_cols,
                 data,
+                layout: $crate::core::matrix::Layout::RowMajor,
             }
         }
     };
@@ -299,7 +1082,10 @@ pub(crate) use matrix;
 
 #[cfg(test)]
 mod test {
+    use approx::assert_relative_eq;
+
     use super::*;
+    use crate::core::complex::Complex;
 
     #[test]
     fn test_gen() {
@@ -313,7 +1099,7 @@ mod test {
     #[test]
     fn test_add() {
         let a = Matrix::from_gen(2, 2, |i, j| (i + j) as i32);
-        let b = Matrix::from_gen(2, 2, |i, j| (i as i32 - j as i32));
+        let b = Matrix::from_gen(2, 2, |i, j| i as i32 - j as i32);
         let c = &a + &b;
         assert_eq!(c[(0, 0)], 0);
         assert_eq!(c[(0, 1)], 0);
@@ -324,7 +1110,7 @@ mod test {
     #[test]
     fn test_sub() {
         let a = Matrix::from_gen(2, 2, |i, j| (i + j) as i32);
-        let b = Matrix::from_gen(2, 2, |i, j| (i as i32 - j as i32));
+        let b = Matrix::from_gen(2, 2, |i, j| i as i32 - j as i32);
         let c = &a - &b;
         assert_eq!(c[(0, 0)], 0);
         assert_eq!(c[(0, 1)], 2);
@@ -347,6 +1133,28 @@ mod test {
         assert_eq!(at[(1, 1)], 4);
     }
 
+    #[test]
+    fn test_swap_rows() {
+        let mut a = matrix![1, 2; 3, 4; 5, 6];
+        a.swap_rows(2, 0);
+        assert_eq!(a[(0, 0)], 5);
+        assert_eq!(a[(0, 1)], 6);
+        assert_eq!(a[(1, 0)], 3);
+        assert_eq!(a[(2, 0)], 1);
+        assert_eq!(a[(2, 1)], 2);
+    }
+
+    #[test]
+    fn test_swap_cols() {
+        let mut a = matrix![1, 2, 3; 4, 5, 6];
+        a.swap_cols(0, 2);
+        assert_eq!(a[(0, 0)], 3);
+        assert_eq!(a[(0, 2)], 1);
+        assert_eq!(a[(1, 0)], 6);
+        assert_eq!(a[(1, 1)], 5);
+        assert_eq!(a[(1, 2)], 4);
+    }
+
     #[test]
     fn test_is_symmetric() {
         let mut a = Matrix::new(2, 2);
@@ -412,4 +1220,350 @@ mod test {
         assert_eq!(m[(1, 1)], 5);
         assert_eq!(m[(1, 2)], 6);
     }
+
+    #[test]
+    fn test_col_major() {
+        let m = Matrix::with_layout(2, 3, vec![1, 4, 2, 5, 3, 6], Layout::ColMajor);
+        assert_eq!(m.strides(), (1, 2));
+        assert_eq!(m[(0, 2)], 3);
+        assert_eq!(m[(1, 0)], 4);
+        assert_eq!(m.to_row_major().data, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.transpose().data, vec![1, 4, 2, 5, 3, 6]);
+
+        let r = matrix![1, 2, 3; 4, 5, 6];
+        assert_eq!(r.to_col_major().data, m.data);
+
+        let sum = &m + &r;
+        assert_eq!(sum.layout(), Layout::RowMajor);
+        assert_eq!(sum.data, vec![2, 4, 6, 8, 10, 12]);
+
+        let mut m = m;
+        m -= &r;
+        assert!(m.data.iter().all(|&x| x == 0));
+    }
+
+    #[test]
+    fn test_into_transpose() {
+        let m = matrix![1, 2, 3; 4, 5, 6];
+        let ptr = m.data.as_ptr();
+
+        let mut t = m.into_transpose();
+        assert_eq!(t.data.as_ptr(), ptr);
+        assert_eq!(t.layout(), Layout::ColMajor);
+        assert_eq!((t.n_rows, t.n_cols), (3, 2));
+        assert_eq!(t[(2, 0)], 3);
+
+        t.swap_rows(0, 2);
+        t.swap_cols(0, 1);
+        assert_eq!(t.to_row_major().data, vec![6, 3, 5, 2, 4, 1]);
+        assert_eq!(t.into_transpose().layout(), Layout::RowMajor);
+    }
+
+    #[test]
+    fn test_strided() {
+        // A 2 x 3 matrix with a leading dimension of 4, padded with zeros
+        let layout = Layout::Strided {
+            row_stride: 4,
+            col_stride: 1,
+        };
+        let m = Matrix::with_layout(2, 3, vec![1, 2, 3, 0, 4, 5, 6], layout);
+        assert_eq!(m[(1, 2)], 6);
+        assert_eq!(m.to_row_major().data, vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(m.view(.., 1..).to_matrix().data, vec![2, 3, 5, 6]);
+
+        let t = m.into_transpose();
+        assert_eq!(t.strides(), (1, 4));
+        assert_eq!(t[(2, 1)], 6);
+    }
+
+    #[test]
+    fn test_strided_add() {
+        // Padding that would overflow if it were added must be skipped
+        let layout = Layout::Strided {
+            row_stride: 3,
+            col_stride: 1,
+        };
+        let a = Matrix::with_layout(2, 2, vec![1, 2, i32::MAX, 3, 4], layout);
+        let b = Matrix::with_layout(2, 2, vec![10, 20, 1, 30, 40], layout);
+
+        assert_eq!(&a + &b, matrix![11, 22; 33, 44]);
+        assert_eq!(&b - &a, matrix![9, 18; 27, 36]);
+
+        let mut c = a.clone();
+        c += &b;
+        assert_eq!(c, matrix![11, 22; 33, 44]);
+        c -= &b;
+        assert_eq!(c, matrix![1, 2; 3, 4]);
+        assert_eq!(c.as_slice()[2], i32::MAX);
+    }
+
+    #[test]
+    fn test_strided_operators() {
+        // The padding holds values that would overflow or divide by zero if operated on
+        let layout = Layout::Strided {
+            row_stride: 3,
+            col_stride: 1,
+        };
+        let a = Matrix::with_layout(2, 2, vec![2, 4, i32::MAX, 6, 8], layout);
+        let b = Matrix::with_layout(2, 2, vec![1, 2, 0, 3, 4], layout);
+
+        assert_eq!(a.hadamard(&b), matrix![2, 8; 18, 32]);
+        assert_eq!(a.hadamard_div(&b), matrix![2, 2; 2, 2]);
+        assert_eq!(&a * 2, matrix![4, 8; 12, 16]);
+        assert_eq!(&a / 2, matrix![1, 2; 3, 4]);
+        assert_eq!(a.clone() * 2, matrix![4, 8; 12, 16]);
+        assert_eq!(a.clone() / 2, matrix![1, 2; 3, 4]);
+
+        let c = Matrix::with_layout(2, 2, vec![2, 4, i32::MIN, 6, 8], layout);
+        assert_eq!(-&c, matrix![-2, -4; -6, -8]);
+        assert_eq!(-c, matrix![-2, -4; -6, -8]);
+
+        let mut d = a.clone();
+        d *= 2;
+        d /= 4;
+        assert_eq!(d, matrix![1, 2; 3, 4]);
+        assert_eq!(d.as_slice()[2], i32::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_overlapping_layout() {
+        let layout = Layout::Strided {
+            row_stride: 2,
+            col_stride: 1,
+        };
+        Matrix::with_layout(2, 3, vec![0; 6], layout);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_zero_stride_layout() {
+        let layout = Layout::Strided {
+            row_stride: 0,
+            col_stride: 0,
+        };
+        Matrix::with_layout(1, 3, vec![0; 3], layout);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_short_layout() {
+        Matrix::with_layout(2, 3, vec![0; 5], Layout::ColMajor);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let offset = 10;
+        let m = Matrix::from_fn(2, 3, |i, j| offset + 3 * i + j);
+        assert_eq!(m.as_slice(), &[10, 11, 12, 13, 14, 15]);
+    }
+
+    #[test]
+    fn test_from_vec() {
+        let m = Matrix::from_vec(2, 2, vec![1, 2, 3, 4]).unwrap();
+        assert_eq!(m[(1, 0)], 3);
+        assert_eq!(m.into_vec(), vec![1, 2, 3, 4]);
+
+        let err = Matrix::from_vec(2, 3, vec![1, 2, 3, 4]).unwrap_err();
+        assert_eq!(
+            err,
+            LengthMismatchError {
+                expected: 6,
+                got: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_rows_cols() {
+        let m = Matrix::from_rows(&[[1, 2, 3], [4, 5, 6]]).unwrap();
+        assert_eq!((m.n_rows, m.n_cols), (2, 3));
+        assert_eq!(m[(1, 2)], 6);
+
+        let m = Matrix::from_cols(&[vec![1, 4], vec![2, 5], vec![3, 6]]).unwrap();
+        assert_eq!(m.layout(), Layout::ColMajor);
+        assert_eq!(m.to_row_major().as_slice(), &[1, 2, 3, 4, 5, 6]);
+
+        let err = Matrix::from_rows(&[vec![1, 2], vec![3]]).unwrap_err();
+        assert_eq!(
+            err,
+            RaggedError {
+                expected: 2,
+                got: 1
+            }
+        );
+
+        let empty = Matrix::<f64>::from_rows::<Vec<f64>>(&[]).unwrap();
+        assert_eq!((empty.n_rows, empty.n_cols), (0, 0));
+    }
+
+    #[test]
+    fn test_conversions() {
+        let m = Matrix::from([[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]]);
+        assert_eq!((m.n_rows, m.n_cols), (3, 2));
+        assert_eq!(m[(2, 1)], 6.0);
+
+        let mut m = Matrix::try_from(vec![vec![1, 2], vec![3, 4]]).unwrap();
+        m.as_mut_slice()[3] = -4;
+        assert_eq!(m[(1, 1)], -4);
+
+        assert!(Matrix::try_from(vec![vec![1], vec![2, 3]]).is_err());
+    }
+
+    #[test]
+    fn test_try_add_sub() {
+        let a = matrix![1, 2; 3, 4];
+        let b = matrix![1, 1; 1, 1];
+
+        assert_eq!(a.try_add(&b).unwrap().as_slice(), &[2, 3, 4, 5]);
+        assert_eq!(a.try_sub(&b).unwrap().as_slice(), &[0, 1, 2, 3]);
+
+        let c = matrix![1, 2, 3];
+        let err = DimensionMismatchError {
+            lhs: (2, 2),
+            rhs: (1, 3),
+        };
+        assert_eq!(a.try_add(&c).unwrap_err(), err);
+        assert_eq!(a.try_sub(&c).unwrap_err(), err);
+    }
+
+    #[test]
+    fn test_operators() {
+        let a: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+        let b = matrix![0.5, -1.0; 2.0, 0.0];
+
+        assert_eq!(a.clone() + b.clone(), &a + &b);
+        assert_eq!(&a + b.clone(), a.clone() + &b);
+        assert_eq!((a.clone() - &b).as_slice(), &[0.5, 3.0, 1.0, 4.0]);
+        assert_eq!(&a * &b, gemm(&a, &b));
+        assert_eq!(a.clone() * b.clone(), gemm(&a, &b));
+
+        let x = Vector::from([1.0, -1.0]);
+        assert_eq!(&a * &x, gemv(&a, &x));
+        assert_eq!(a.clone() * x.clone(), Vector::from([-1.0, -1.0]));
+
+        assert_eq!((2.0 * &a).as_slice(), &[2.0, 4.0, 6.0, 8.0]);
+        assert_eq!(&a * 2.0, 2.0 * a.clone());
+        assert_eq!((&a / 2.0).as_slice(), &[0.5, 1.0, 1.5, 2.0]);
+        assert_eq!(a.clone() / 2.0, &a * 0.5);
+        assert_eq!((-&a).as_slice(), &[-1.0, -2.0, -3.0, -4.0]);
+        assert_eq!(-a.clone(), -1.0 * &a);
+
+        let mut c = a.clone();
+        c *= 3.0;
+        c /= 1.5;
+        assert_eq!(c, 2.0 * &a);
+    }
+
+    #[test]
+    fn test_eq_padding() {
+        let layout = Layout::Strided {
+            row_stride: 3,
+            col_stride: 1,
+        };
+        let a = Matrix::with_layout(2, 2, vec![1, 2, 0, 3, 4], layout);
+        let b = Matrix::with_layout(2, 2, vec![1, 2, 9, 3, 4], layout);
+        assert_eq!(a, b);
+        assert_ne!(a, Matrix::with_layout(2, 2, vec![1, 2, 0, 3, 5], layout));
+    }
+
+    #[test]
+    fn test_hadamard() {
+        let a = matrix![1, 2; 3, 4];
+        let b = matrix![2, 2; 3, 2];
+
+        assert_eq!(a.hadamard(&b).as_slice(), &[2, 4, 9, 8]);
+        assert_eq!(a.hadamard_div(&b).as_slice(), &[0, 1, 1, 2]);
+        assert_eq!(a.to_col_major().hadamard(&b), a.hadamard(&b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_hadamard_dims() {
+        matrix![1, 2].hadamard(&matrix![1; 2]);
+    }
+
+    #[test]
+    fn test_eq_layouts() {
+        let a = matrix![1, 2, 3; 4, 5, 6];
+
+        assert_eq!(a, a.to_col_major());
+        assert_ne!(a, matrix![1, 2, 3; 4, 5, 7]);
+        assert_ne!(a, a.transpose());
+    }
+
+    #[test]
+    fn test_adjoint_hermitian() {
+        use crate::core::complex::Complex;
+
+        let z = |re, im| Complex::new(re, im);
+        let a: Matrix<Complex<f64>> =
+            matrix![z(2.0, 0.0), z(1.0, -1.0), z(0.0, 3.0); z(1.0, 1.0), z(5.0, 0.0), z(-2.0, 1.0)];
+
+        let adj = a.adjoint();
+        assert_eq!((adj.n_rows, adj.n_cols), (3, 2));
+        assert_eq!(adj[(2, 0)], z(0.0, -3.0));
+        assert_eq!(adj[(0, 1)], z(1.0, -1.0));
+        assert_eq!(adj.adjoint(), a);
+        assert!(!a.is_hermitian());
+
+        let h = matrix![z(2.0, 0.0), z(1.0, -1.0); z(1.0, 1.0), z(5.0, 0.0)];
+        assert!(h.is_hermitian());
+        assert!(!h.is_symmetric());
+        // The diagonal of a Hermitian matrix is real
+        assert!(!matrix![z(2.0, 1.0)].is_hermitian());
+
+        let m: Matrix<f64> = matrix![1.0, 2.0; 2.0, 3.0];
+        assert_eq!(m.adjoint(), m.transpose());
+        assert!(m.is_hermitian());
+    }
+
+    #[test]
+    fn test_norms() {
+        let a: Matrix<f64> = matrix![1.0, -2.0, 3.0; -4.0, 5.0, -6.0];
+
+        assert_eq!(a.norm1(), 9.0);
+        assert_eq!(a.norm_inf(), 15.0);
+        assert_eq!(a.norm_max(), 6.0);
+        assert_relative_eq!(a.norm_fro(), 91.0f64.sqrt());
+        for m in [a.to_col_major(), a.transpose().into_transpose()] {
+            assert_eq!((m.norm1(), m.norm_inf(), m.norm_max()), (9.0, 15.0, 6.0));
+            assert_relative_eq!(m.norm_fro(), a.norm_fro());
+        }
+        assert_eq!(a.transpose().norm1(), a.norm_inf());
+
+        let empty = Matrix::<f64>::zeros(0, 3);
+        assert_eq!(
+            (empty.norm1(), empty.norm_inf(), empty.norm_fro()),
+            (0.0, 0.0, 0.0)
+        );
+        // Squaring the entries would overflow
+        assert_relative_eq!(matrix![3e200, 4e200].norm_fro(), 5e200);
+
+        let z = |re, im| Complex::new(re, im);
+        let c = matrix![z(3.0, 4.0), z(0.0, 1.0); z(0.0, 0.0), z(-2.0, 0.0)];
+        assert_eq!((c.norm1(), c.norm_inf(), c.norm_max()), (5.0, 6.0, 5.0));
+        assert_relative_eq!(c.norm_fro(), 30.0f64.sqrt());
+    }
+
+    #[test]
+    fn test_trace_diag() {
+        let a = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+        assert_eq!(a.trace(), 15);
+        assert_eq!(a.diag(), Vector::from([1, 5, 9]));
+        assert_eq!(a.to_col_major().diag(), a.diag());
+        assert_eq!(matrix![1, 2, 3; 4, 5, 6].diag(), Vector::from([1, 5]));
+        assert_eq!(Matrix::<f64>::zeros(0, 0).trace(), 0.0);
+
+        let d = Matrix::from_diag(&Vector::from([2.0, -1.0]));
+        assert_eq!(d, matrix![2.0, 0.0; 0.0, -1.0]);
+        assert_eq!(Matrix::from_diag(&d.diag()), d);
+        assert_eq!(Matrix::<i32>::from_diag(&Vector::ones(3)), Matrix::eye(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_trace_non_square() {
+        matrix![1, 2, 3; 4, 5, 6].trace();
+    }
 }
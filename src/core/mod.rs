@@ -1,4 +1,14 @@
+pub mod complex;
+pub mod double_double;
 pub mod error;
 pub mod gemm;
+pub(crate) mod kernel;
 pub mod matrix;
+pub mod parallel;
+pub mod permutation;
+#[cfg(feature = "rational")]
+pub mod rational;
+pub mod scalar;
+pub(crate) mod simd;
 pub mod vector;
+pub mod view;
@@ -0,0 +1,154 @@
+//! Row-block parallelism for the dense kernels, enabled by the `parallel` cargo feature.
+//!
+//! Without the feature every helper here runs its blocks in order on the calling thread. Work
+//! is split the same way in both cases and each output element is computed by exactly one
+//! block, so parallel results are bit-identical to serial ones.
+
+#[cfg(feature = "parallel")]
+use std::sync::{Arc, Mutex};
+
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+
+/// Elements per block below which splitting work across threads is not worth it.
+const MIN_BLOCK_LEN: usize = 1 << 14;
+
+/// Bound required of element types so they can be shared across threads. Equivalent to
+/// `Send + Sync` with the `parallel` feature and satisfied by every type without it.
+#[cfg(feature = "parallel")]
+pub trait MaybeSync: Send + Sync {}
+
+#[cfg(feature = "parallel")]
+impl<T: Send + Sync> MaybeSync for T {}
+
+/// Bound required of element types so they can be shared across threads. Equivalent to
+/// `Send + Sync` with the `parallel` feature and satisfied by every type without it.
+#[cfg(not(feature = "parallel"))]
+pub trait MaybeSync {}
+
+#[cfg(not(feature = "parallel"))]
+impl<T> MaybeSync for T {}
+
+#[cfg(feature = "parallel")]
+static POOL: Mutex<Option<(usize, Arc<rayon::ThreadPool>)>> = Mutex::new(None);
+
+/// Sets the number of threads used by the parallel kernels. Zero, the default, uses one
+/// thread per logical CPU.
+#[cfg(feature = "parallel")]
+pub fn set_num_threads(n: usize) {
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(n)
+        .build()
+        .expect("failed to build thread pool");
+    *POOL.lock().unwrap() = Some((n, Arc::new(pool)));
+}
+
+/// Number of threads used by the parallel kernels.
+#[cfg(feature = "parallel")]
+pub fn num_threads() -> usize {
+    pool().current_num_threads()
+}
+
+#[cfg(feature = "parallel")]
+fn pool() -> Arc<rayon::ThreadPool> {
+    let mut pool = POOL.lock().unwrap();
+    let (_, pool) = pool.get_or_insert_with(|| {
+        let default = rayon::ThreadPoolBuilder::new()
+            .build()
+            .expect("failed to build thread pool");
+        (0, Arc::new(default))
+    });
+
+    Arc::clone(pool)
+}
+
+/// Number of rows of length `row_len` per block, so that a block holds at least
+/// `MIN_BLOCK_LEN` elements.
+pub(crate) fn rows_per_block(row_len: usize) -> usize {
+    MIN_BLOCK_LEN.div_ceil(row_len.max(1))
+}
+
+/// Calls `f(first_row, block)` on consecutive blocks of `rows_per_block` rows of the row-major
+/// buffer `data`, in parallel with the `parallel` feature.
+pub(crate) fn for_each_row_block<T, F>(data: &mut [T], row_len: usize, rows_per_block: usize, f: F)
+where
+    T: MaybeSync,
+    F: Fn(usize, &mut [T]) + MaybeSync,
+{
+    if data.is_empty() || row_len == 0 {
+        return;
+    }
+
+    let block_len = rows_per_block * row_len;
+    #[cfg(feature = "parallel")]
+    if data.len() > block_len {
+        pool().install(|| {
+            data.par_chunks_mut(block_len)
+                .enumerate()
+                .for_each(|(b, block)| f(b * rows_per_block, block))
+        });
+        return;
+    }
+
+    data.chunks_mut(block_len)
+        .enumerate()
+        .for_each(|(b, block)| f(b * rows_per_block, block));
+}
+
+/// Calls `f` on every item, in parallel with the `parallel` feature.
+pub(crate) fn for_each<I, F>(items: Vec<I>, f: F)
+where
+    I: MaybeSync,
+    F: Fn(I) + MaybeSync,
+{
+    #[cfg(feature = "parallel")]
+    if items.len() > 1 {
+        pool().install(|| items.into_par_iter().for_each(f));
+        return;
+    }
+
+    items.into_iter().for_each(f);
+}
+
+/// Computes `f(x, y)` for corresponding elements of `lhs` and `rhs`, in parallel blocks with
+/// the `parallel` feature.
+pub(crate) fn zip_map<T, U, F>(lhs: &[T], rhs: &[T], f: F) -> Vec<U>
+where
+    T: MaybeSync,
+    U: MaybeSync,
+    F: Fn(&T, &T) -> U + MaybeSync,
+{
+    #[cfg(feature = "parallel")]
+    if lhs.len() > MIN_BLOCK_LEN {
+        let f = &f;
+        return pool().install(|| {
+            lhs.par_chunks(MIN_BLOCK_LEN)
+                .zip(rhs.par_chunks(MIN_BLOCK_LEN))
+                .flat_map_iter(|(x, y)| x.iter().zip(y).map(|(x, y)| f(x, y)))
+                .collect()
+        });
+    }
+
+    lhs.iter().zip(rhs).map(|(x, y)| f(x, y)).collect()
+}
+
+#[cfg(all(test, feature = "parallel"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_num_threads() {
+        set_num_threads(3);
+        assert_eq!(num_threads(), 3);
+
+        let mut data = vec![0usize; 10 * MIN_BLOCK_LEN];
+        for_each_row_block(&mut data, 100, rows_per_block(100), |row, block| {
+            for (i, x) in block.iter_mut().enumerate() {
+                *x = row * 100 + i;
+            }
+        });
+        assert!(data.iter().enumerate().all(|(i, &x)| x == i));
+
+        set_num_threads(0);
+    }
+}
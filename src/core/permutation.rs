@@ -0,0 +1,150 @@
+use std::ops::Index;
+
+use crate::core::matrix::{Layout, Matrix};
+use crate::core::vector::Vector;
+
+/// A permutation of `n` indices, stored as the source index of every position.
+///
+/// Applied to the rows of a matrix `A`, row `i` of `P * A` is row `perm[i]` of `A`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Permutation {
+    pub n: usize,
+    pub(crate) perm: Vec<usize>,
+    pub(crate) sign: i32,
+}
+
+impl Permutation {
+    pub fn identity(n: usize) -> Self {
+        Self {
+            n,
+            perm: (0..n).collect(),
+            sign: 1,
+        }
+    }
+
+    /// Swaps positions `i` and `j`, flipping the sign if they differ.
+    pub fn swap(&mut self, i: usize, j: usize) {
+        if i != j {
+            self.perm.swap(i, j);
+            self.sign = -self.sign;
+        }
+    }
+
+    /// Sign of the permutation: `1` for an even number of transpositions, `-1` otherwise.
+    pub fn sign(&self) -> i32 {
+        self.sign
+    }
+
+    pub fn inverse(&self) -> Self {
+        let mut perm = vec![0; self.n];
+        for (i, &p) in self.perm.iter().enumerate() {
+            perm[p] = i;
+        }
+
+        Self {
+            n: self.n,
+            perm,
+            sign: self.sign,
+        }
+    }
+
+    /// Computes `P * v`.
+    pub fn apply_vec<T>(&self, v: &Vector<T>) -> Vector<T>
+    where
+        T: Clone,
+    {
+        if self.n != v.n {
+            panic!(
+                "Cannot permute vector of size {} with permutation of size {}",
+                v.n, self.n
+            );
+        }
+
+        let data = self.perm.iter().map(|&p| v.data[p].clone()).collect();
+        Vector { n: self.n, data }
+    }
+
+    /// Computes `P * m`, i.e. permutes the rows of `m`.
+    pub fn apply_rows<T>(&self, m: &Matrix<T>) -> Matrix<T>
+    where
+        T: Copy + Clone + Default,
+    {
+        if self.n != m.n_rows {
+            panic!(
+                "Cannot permute matrix with {} rows with permutation of size {}",
+                m.n_rows, self.n
+            );
+        }
+
+        let mut data = Vec::with_capacity(m.n_rows * m.n_cols);
+        for &p in self.perm.iter() {
+            if m.layout == Layout::RowMajor {
+                data.extend_from_slice(&m.data[p * m.n_cols..(p + 1) * m.n_cols]);
+            } else {
+                data.extend((0..m.n_cols).map(|j| m[(p, j)]));
+            }
+        }
+
+        Matrix {
+            n_rows: m.n_rows,
+            n_cols: m.n_cols,
+            data,
+            layout: Layout::RowMajor,
+        }
+    }
+}
+
+impl Index<usize> for Permutation {
+    type Output = usize;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.perm[index]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_swap_sign() {
+        let mut p = Permutation::identity(3);
+        assert_eq!(p.sign(), 1);
+
+        p.swap(0, 2);
+        assert_eq!(p.sign(), -1);
+        assert_eq!(p[0], 2);
+        assert_eq!(p[2], 0);
+
+        p.swap(1, 1);
+        assert_eq!(p.sign(), -1);
+
+        p.swap(0, 1);
+        assert_eq!(p.sign(), 1);
+    }
+
+    #[test]
+    fn test_apply() {
+        let mut p = Permutation::identity(3);
+        p.swap(0, 2);
+        p.swap(1, 2);
+
+        let v = Vector::from_vec(&[10, 20, 30]);
+        let pv = p.apply_vec(&v);
+        assert_eq!(pv[0], 30);
+        assert_eq!(pv[1], 10);
+        assert_eq!(pv[2], 20);
+
+        let m = matrix![1, 2; 3, 4; 5, 6];
+        let pm = p.apply_rows(&m);
+        assert_eq!(pm[(0, 0)], 5);
+        assert_eq!(pm[(1, 1)], 2);
+        assert_eq!(pm[(2, 0)], 3);
+
+        let back = p.inverse().apply_vec(&pv);
+        for i in 0..3 {
+            assert_eq!(back[i], v[i]);
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Exact rational scalars, enabled by the `rational` cargo feature.
+//!
+//! `Rational` is the arbitrary-precision fraction of the `num-rational` crate. It implements
+//! `Field`, so LU factorizations, with their `solve`, `det` and `inverse`, can be computed on it
+//! without any rounding, to check results that are numerically delicate in floating point.
+
+pub use num_bigint::BigInt;
+use num_bigint::Sign;
+use num_rational::Ratio;
+
+use crate::core::scalar::{Field, One, Zero};
+
+/// Fraction of arbitrary-precision integers, always in lowest terms.
+pub type Rational = Ratio<BigInt>;
+
+impl Zero for Rational {
+    fn zero() -> Self {
+        Ratio::from_integer(BigInt::from(0))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer().sign() == Sign::NoSign
+    }
+}
+
+impl One for Rational {
+    fn one() -> Self {
+        Ratio::from_integer(BigInt::from(1))
+    }
+}
+
+impl Field for Rational {
+    type Magnitude = Rational;
+
+    fn magnitude(&self) -> Rational {
+        if self.numer().sign() == Sign::Minus {
+            -self
+        } else {
+            self.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::core::matrix::Matrix;
+    use crate::core::vector::Vector;
+    use crate::linalg::inverse::{Determinant, Inverse};
+    use crate::linalg::lu::LU;
+    use crate::linalg::solve::Solve;
+
+    fn int(n: i64) -> Rational {
+        Ratio::from_integer(BigInt::from(n))
+    }
+
+    fn binomial(n: i64, k: i64) -> BigInt {
+        (0..k).fold(BigInt::from(1), |acc, i| acc * (n - i) / (i + 1))
+    }
+
+    #[test]
+    fn test_lu_integer() {
+        let a = Matrix::from_fn(
+            3,
+            3,
+            |i, j| int([[2, -1, 0], [-1, 2, -1], [0, -1, 2]][i][j]),
+        );
+        let lu = a.lu().unwrap();
+
+        assert_eq!(lu.det(), int(4));
+        assert_eq!(lu.u()[(2, 2)], Ratio::new(BigInt::from(4), BigInt::from(3)));
+        assert_eq!(
+            lu.solve(&Vector::from_fn(3, |i| int([1, 0, 1][i]))),
+            Vector::from_fn(3, |_| int(1))
+        );
+
+        let rank_one = Matrix::from_fn(2, 2, |i, j| int(((i + 1) * (j + 1)) as i64));
+        assert!(rank_one.lu().is_err());
+        assert_eq!(rank_one.det().unwrap(), int(0));
+    }
+
+    #[test]
+    fn test_hilbert_inverse() {
+        let n = 8;
+        let hilbert = Matrix::from_fn(n, n, |i, j| {
+            Ratio::new(BigInt::from(1), BigInt::from(i + j + 1))
+        });
+
+        // Entry (i, j) of the inverse is
+        // (-1)^(i + j) (i + j + 1) C(n + i, n - j - 1) C(n + j, n - i - 1) C(i + j, i)^2
+        let expected = Matrix::from_fn(n, n, |i, j| {
+            let (n, i, j) = (n as i64, i as i64, j as i64);
+            let sign = if (i + j) % 2 == 0 { 1 } else { -1 };
+            let c = binomial(i + j, i);
+            Ratio::from_integer(
+                BigInt::from(sign * (i + j + 1))
+                    * binomial(n + i, n - j - 1)
+                    * binomial(n + j, n - i - 1)
+                    * &c
+                    * c,
+            )
+        });
+
+        let lu = hilbert.lu().unwrap();
+        assert_eq!(lu.inverse(), expected);
+        assert_eq!(hilbert.inverse().unwrap(), expected);
+
+        // The determinant is the reciprocal of an integer
+        let det = lu.det();
+        assert_eq!(*det.numer(), BigInt::from(1));
+        assert_eq!(det.denom().to_string(), "365356847125734485878112256000000");
+        assert_eq!(hilbert.det().unwrap(), det);
+
+        let b = Vector::from_fn(n, |i| (0..n).map(|j| hilbert[(i, j)].clone()).sum());
+        assert_eq!(lu.solve(&b), Vector::from_fn(n, |_| int(1)));
+        assert_eq!(hilbert.solve(&b).unwrap(), Vector::from_fn(n, |_| int(1)));
+    }
+}
@@ -0,0 +1,379 @@
+//! Numeric traits the generic code in this crate is written against.
+//!
+//! `Zero` and `One` provide the additive and multiplicative identities, `Scalar` is any copyable
+//! number with ring arithmetic, which is enough for constructors and products, and `RealField`
+//! adds the division, ordering and elementary functions needed by the decompositions.
+//! `ComplexField` covers both real and complex numbers, for algorithms that only need
+//! conjugation and a modulus, and is implemented for every `RealField` and for `Complex`.
+//!
+//! `Field` is the weakest of these traits, with the field operations and a magnitude to choose
+//! pivots by. It is all the LU decomposition and the `det` and `inverse` built on it need, and
+//! unlike the others does not require `Copy`, so exact and arbitrary-precision types such as
+//! `Ratio<BigInt>` can implement it. It is implemented for every `ComplexField`.
+//!
+//! `Scalar` is implemented for every type with the required operations, so a custom number type
+//! only has to implement `Zero` and `One` to be used as a matrix element, and `RealField` to be
+//! factorized.
+
+use std::fmt::Debug;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::core::complex::Complex;
+use crate::core::parallel::MaybeSync;
+
+/// Additive identity.
+pub trait Zero: Sized {
+    fn zero() -> Self;
+    fn is_zero(&self) -> bool;
+}
+
+/// Multiplicative identity.
+pub trait One: Sized {
+    fn one() -> Self;
+}
+
+/// Copyable number with addition, subtraction and multiplication.
+pub trait Scalar:
+    Copy
+    + Debug
+    + Default
+    + PartialEq
+    + MaybeSync
+    + 'static
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+{
+}
+
+impl<T> Scalar for T where
+    T: Copy
+        + Debug
+        + Default
+        + PartialEq
+        + MaybeSync
+        + 'static
+        + Zero
+        + One
+        + Add<Output = T>
+        + Sub<Output = T>
+        + Mul<Output = T>
+        + AddAssign
+        + SubAssign
+        + MulAssign
+{
+}
+
+/// Ordered field approximating the real numbers, such as `f32` and `f64`.
+pub trait RealField:
+    Scalar + PartialOrd + Neg<Output = Self> + Div<Output = Self> + DivAssign + Sum
+{
+    /// Machine epsilon, the difference between 1 and the next representable value.
+    fn epsilon() -> Self;
+    fn abs(self) -> Self;
+    fn sqrt(self) -> Self;
+    /// Natural logarithm.
+    fn ln(self) -> Self;
+
+    /// Whether the value is neither infinite nor NaN. The default relies on `0 * x` being zero
+    /// for finite `x` and NaN otherwise, as in IEEE arithmetic.
+    fn is_finite(self) -> bool {
+        Self::zero() * self == Self::zero()
+    }
+}
+
+/// Field, with exact arithmetic like the rationals or approximate like the floats.
+pub trait Field:
+    Clone
+    + Debug
+    + PartialEq
+    + MaybeSync
+    + 'static
+    + Zero
+    + One
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+    + AddAssign
+    + SubAssign
+    + MulAssign
+    + DivAssign
+{
+    /// Type of `magnitude`, `Self::Real` for complex fields.
+    type Magnitude: PartialOrd + Zero;
+
+    /// Size by which pivots are chosen, the absolute value for real and complex numbers.
+    fn magnitude(&self) -> Self::Magnitude;
+}
+
+/// Field of real or complex numbers, with conjugation and a real-valued modulus.
+pub trait ComplexField: Scalar + Neg<Output = Self> + Div<Output = Self> + DivAssign {
+    /// Real numbers this field is built on, `Self` for real fields.
+    type Real: RealField;
+
+    fn from_real(re: Self::Real) -> Self;
+    /// Real part.
+    fn re(self) -> Self::Real;
+    /// Complex conjugate, the identity for real fields.
+    fn conj(self) -> Self;
+    /// Absolute value, computed without undue overflow or underflow.
+    fn modulus(self) -> Self::Real;
+    /// Squared absolute value.
+    fn modulus_sqr(self) -> Self::Real;
+}
+
+impl<T> ComplexField for T
+where
+    T: RealField,
+{
+    type Real = T;
+
+    fn from_real(re: T) -> Self {
+        re
+    }
+
+    fn re(self) -> T {
+        self
+    }
+
+    fn conj(self) -> Self {
+        self
+    }
+
+    fn modulus(self) -> T {
+        self.abs()
+    }
+
+    fn modulus_sqr(self) -> T {
+        self * self
+    }
+}
+
+impl<T> ComplexField for Complex<T>
+where
+    T: RealField,
+{
+    type Real = T;
+
+    fn from_real(re: T) -> Self {
+        Complex::new(re, T::zero())
+    }
+
+    fn re(self) -> T {
+        self.re
+    }
+
+    fn conj(self) -> Self {
+        Complex::new(self.re, -self.im)
+    }
+
+    fn modulus(self) -> T {
+        // Scale by the larger component so that squaring cannot overflow
+        let (re, im) = (self.re.abs(), self.im.abs());
+        let (large, small) = if re >= im { (re, im) } else { (im, re) };
+        if large.is_zero() {
+            return large;
+        }
+        let ratio = small / large;
+
+        large * (T::one() + ratio * ratio).sqrt()
+    }
+
+    fn modulus_sqr(self) -> T {
+        self.norm_sqr()
+    }
+}
+
+impl<T> Field for T
+where
+    T: ComplexField,
+{
+    type Magnitude = T::Real;
+
+    fn magnitude(&self) -> T::Real {
+        self.modulus()
+    }
+}
+
+macro_rules! impl_zero_one {
+    ($zero:expr, $one:expr; $($type:ty),*) => {
+        $(
+            impl Zero for $type {
+                fn zero() -> Self {
+                    $zero
+                }
+
+                fn is_zero(&self) -> bool {
+                    *self == $zero
+                }
+            }
+
+            impl One for $type {
+                fn one() -> Self {
+                    $one
+                }
+            }
+        )*
+    };
+}
+
+impl_zero_one!(0, 1; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_zero_one!(0.0, 1.0; f32, f64);
+
+macro_rules! impl_real_field {
+    ($type:ty) => {
+        impl RealField for $type {
+            fn epsilon() -> Self {
+                <$type>::EPSILON
+            }
+
+            fn abs(self) -> Self {
+                <$type>::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                <$type>::sqrt(self)
+            }
+
+            fn ln(self) -> Self {
+                <$type>::ln(self)
+            }
+
+            fn is_finite(self) -> bool {
+                <$type>::is_finite(self)
+            }
+        }
+    };
+}
+
+impl_real_field!(f32);
+impl_real_field!(f64);
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+
+    use super::*;
+    use crate::core::matrix::Matrix;
+    use crate::core::vector::Vector;
+    use crate::linalg::chol::Cholesky;
+    use crate::linalg::lu::LU;
+    use crate::linalg::solve::Solve;
+    use crate::matrix;
+
+    /// Real number type defined outside the crate's list of primitives.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, PartialOrd)]
+    struct Real(f64);
+
+    macro_rules! real_binop {
+        ($imp:ident, $method:ident, $imp_assign:ident, $method_assign:ident) => {
+            impl $imp for Real {
+                type Output = Self;
+
+                fn $method(self, rhs: Self) -> Self {
+                    Real(self.0.$method(rhs.0))
+                }
+            }
+
+            impl $imp_assign for Real {
+                fn $method_assign(&mut self, rhs: Self) {
+                    self.0.$method_assign(rhs.0);
+                }
+            }
+        };
+    }
+
+    real_binop!(Add, add, AddAssign, add_assign);
+    real_binop!(Sub, sub, SubAssign, sub_assign);
+    real_binop!(Mul, mul, MulAssign, mul_assign);
+    real_binop!(Div, div, DivAssign, div_assign);
+
+    impl Neg for Real {
+        type Output = Self;
+
+        fn neg(self) -> Self {
+            Real(-self.0)
+        }
+    }
+
+    impl Sum for Real {
+        fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+            Real(iter.map(|x| x.0).sum())
+        }
+    }
+
+    impl Zero for Real {
+        fn zero() -> Self {
+            Real(0.0)
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 == 0.0
+        }
+    }
+
+    impl One for Real {
+        fn one() -> Self {
+            Real(1.0)
+        }
+    }
+
+    impl RealField for Real {
+        fn epsilon() -> Self {
+            Real(f64::EPSILON)
+        }
+
+        fn abs(self) -> Self {
+            Real(self.0.abs())
+        }
+
+        fn sqrt(self) -> Self {
+            Real(self.0.sqrt())
+        }
+
+        fn ln(self) -> Self {
+            Real(self.0.ln())
+        }
+    }
+
+    #[test]
+    fn test_integer_constructors() {
+        let eye = Matrix::<u8>::eye(3);
+        assert_eq!(eye.as_slice(), &[1, 0, 0, 0, 1, 0, 0, 0, 1]);
+        assert_eq!((3 * &eye)[(2, 2)], 3);
+
+        assert_eq!(Matrix::<i16>::ones(2, 3).as_slice(), &[1; 6]);
+        assert_eq!(Matrix::<usize>::zeros(3, 2).as_slice(), &[0; 6]);
+        assert!(Matrix::<u64>::zeros(2, 2)[(1, 0)].is_zero());
+    }
+
+    #[test]
+    fn test_custom_real_field() {
+        let a: Matrix<f64> = matrix![4.0, 12.0, -16.0; 12.0, 37.0, -43.0; -16.0, -43.0, 98.0];
+        let b = Vector::from([-4.0, -9.5, 33.0]);
+        let expected = a.lu().unwrap().solve(&b);
+
+        let a = Matrix::from_fn(3, 3, |i, j| Real(a[(i, j)]));
+        let b = Vector::from_fn(3, |i| Real(b[i]));
+
+        let x = a.solve(&b).unwrap();
+        let y = a.lu().unwrap().solve(&b);
+        let z = a.chol().unwrap().solve(&b);
+        for i in 0..3 {
+            assert_relative_eq!(x[i].0, expected[i], epsilon = 1e-10);
+            assert_relative_eq!(y[i].0, expected[i], epsilon = 1e-10);
+            assert_relative_eq!(z[i].0, expected[i], epsilon = 1e-10);
+        }
+        assert_eq!(Matrix::<Real>::eye(2)[(1, 1)], Real::one());
+        assert!(Real(-2.5).is_finite());
+        assert!(!Real(f64::INFINITY).is_finite() && !Real(f64::NAN).is_finite());
+    }
+}
@@ -0,0 +1,536 @@
+//! Explicitly vectorized kernels for `f32` and `f64`.
+//!
+//! On `x86_64` the widest instruction set available at runtime is used: AVX2 with FMA, or the
+//! SSE2 baseline. Other targets and element types use portable scalar loops.
+
+use std::any::TypeId;
+use std::slice;
+
+#[cfg(target_arch = "x86_64")]
+use std::arch::x86_64::*;
+
+use crate::core::scalar::RealField;
+
+/// Rows of the tile computed by the gemm microkernel.
+pub(crate) const MR: usize = 6;
+/// Largest number of columns of the tile computed by the gemm microkernel, see `nr`.
+pub(crate) const NR_MAX: usize = 16;
+
+/// Microkernel computing the `MR x nr` tile `ab = A_panel * B_panel` from packed panels of
+/// depth `kc`, with `ab` stored row-major.
+pub(crate) type Microkernel<T> = fn(kc: usize, a_panel: &[T], b_panel: &[T], ab: &mut [T]);
+
+/// Columns of the tile computed by the gemm microkernel for `T`: two AVX2 registers.
+pub(crate) fn nr<T: 'static>() -> usize {
+    if is::<T, f32>() {
+        16
+    } else {
+        8
+    }
+}
+
+pub(crate) fn is<T: 'static, F: 'static>() -> bool {
+    TypeId::of::<T>() == TypeId::of::<F>()
+}
+
+/// Reinterprets `x` as `&[F]` if `T` is `F`.
+pub(crate) fn cast<T: 'static, F: 'static>(x: &[T]) -> Option<&[F]> {
+    // SAFETY: `T` and `F` are the same type
+    is::<T, F>().then(|| unsafe { slice::from_raw_parts(x.as_ptr() as *const F, x.len()) })
+}
+
+/// Reinterprets `x` as `&mut [F]` if `T` is `F`.
+pub(crate) fn cast_mut<T: 'static, F: 'static>(x: &mut [T]) -> Option<&mut [F]> {
+    // SAFETY: `T` and `F` are the same type
+    is::<T, F>().then(|| unsafe { slice::from_raw_parts_mut(x.as_mut_ptr() as *mut F, x.len()) })
+}
+
+/// Reinterprets `x` as `F` if `T` is `F`.
+pub(crate) fn cast_scalar<T: Copy + 'static, F: Copy + 'static>(x: T) -> Option<F> {
+    // SAFETY: `T` and `F` are the same type
+    is::<T, F>().then(|| unsafe { std::mem::transmute_copy(&x) })
+}
+
+#[cfg(target_arch = "x86_64")]
+fn has_avx2() -> bool {
+    is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma")
+}
+
+/// Computes `sum(x[i] * y[i])`.
+pub(crate) fn dot<T>(x: &[T], y: &[T]) -> T
+where
+    T: RealField,
+{
+    debug_assert_eq!(x.len(), y.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if let (Some(x), Some(y)) = (cast::<T, f64>(x), cast::<T, f64>(y)) {
+            // SAFETY: the required target features are checked at runtime, and SSE2 is part of
+            // the x86_64 baseline
+            let s = unsafe {
+                if has_avx2() {
+                    dot_f64_avx2(x, y)
+                } else {
+                    dot_f64_sse2(x, y)
+                }
+            };
+            return cast_scalar(s).unwrap();
+        }
+        if let (Some(x), Some(y)) = (cast::<T, f32>(x), cast::<T, f32>(y)) {
+            // SAFETY: as above
+            let s = unsafe {
+                if has_avx2() {
+                    dot_f32_avx2(x, y)
+                } else {
+                    dot_f32_sse2(x, y)
+                }
+            };
+            return cast_scalar(s).unwrap();
+        }
+    }
+
+    dot_scalar(x, y)
+}
+
+/// Computes `y += alpha * x`.
+pub(crate) fn axpy<T>(alpha: T, x: &[T], y: &mut [T])
+where
+    T: RealField,
+{
+    debug_assert_eq!(x.len(), y.len());
+
+    #[cfg(target_arch = "x86_64")]
+    {
+        if is::<T, f64>() {
+            let alpha = cast_scalar(alpha).unwrap();
+            let (x, y) = (cast::<T, f64>(x).unwrap(), cast_mut::<T, f64>(y).unwrap());
+            // SAFETY: as in `dot`
+            unsafe {
+                if has_avx2() {
+                    axpy_f64_avx2(alpha, x, y)
+                } else {
+                    axpy_f64_sse2(alpha, x, y)
+                }
+            }
+            return;
+        }
+        if is::<T, f32>() {
+            let alpha = cast_scalar(alpha).unwrap();
+            let (x, y) = (cast::<T, f32>(x).unwrap(), cast_mut::<T, f32>(y).unwrap());
+            // SAFETY: as in `dot`
+            unsafe {
+                if has_avx2() {
+                    axpy_f32_avx2(alpha, x, y)
+                } else {
+                    axpy_f32_sse2(alpha, x, y)
+                }
+            }
+            return;
+        }
+    }
+
+    axpy_scalar(alpha, x, y)
+}
+
+/// Selects the fastest microkernel for `T` on this CPU.
+pub(crate) fn microkernel<T>() -> Microkernel<T>
+where
+    T: RealField,
+{
+    #[cfg(target_arch = "x86_64")]
+    {
+        let avx2 = has_avx2();
+        if is::<T, f64>() {
+            return if avx2 {
+                microkernel_f64_avx2::<T>
+            } else {
+                microkernel_f64_sse2::<T>
+            };
+        }
+        if is::<T, f32>() {
+            return if avx2 {
+                microkernel_f32_avx2::<T>
+            } else {
+                microkernel_f32_sse2::<T>
+            };
+        }
+    }
+
+    microkernel_scalar::<T>
+}
+
+fn dot_scalar<T>(x: &[T], y: &[T]) -> T
+where
+    T: RealField,
+{
+    let mut s = T::zero();
+    for (&xi, &yi) in x.iter().zip(y) {
+        s += xi * yi;
+    }
+
+    s
+}
+
+fn axpy_scalar<T>(alpha: T, x: &[T], y: &mut [T])
+where
+    T: RealField,
+{
+    for (&xi, yi) in x.iter().zip(y) {
+        *yi += alpha * xi;
+    }
+}
+
+fn microkernel_scalar<T>(kc: usize, a_panel: &[T], b_panel: &[T], ab: &mut [T])
+where
+    T: RealField,
+{
+    let nr = nr::<T>();
+    ab[..MR * nr].fill(T::zero());
+    for (a, b) in a_panel
+        .chunks_exact(MR)
+        .zip(b_panel.chunks_exact(nr))
+        .take(kc)
+    {
+        for i in 0..MR {
+            for j in 0..nr {
+                ab[i * nr + j] += a[i] * b[j];
+            }
+        }
+    }
+}
+
+/// Generates a dot product kernel with four independent vector accumulators.
+macro_rules! simd_dot {
+    ($name:ident, $type:ty, $feature:literal, $lanes:expr, $zero:ident, $load:ident, $mul_add:expr, $add:ident, $store:ident) => {
+        #[cfg(target_arch = "x86_64")]
+        #[target_feature(enable = $feature)]
+        unsafe fn $name(x: &[$type], y: &[$type]) -> $type {
+            const LANES: usize = $lanes;
+            let n = x.len().min(y.len());
+            let (px, py) = (x.as_ptr(), y.as_ptr());
+
+            let mut acc = [$zero(); 4];
+            let mut i = 0;
+            while i + 4 * LANES <= n {
+                for (u, acc) in acc.iter_mut().enumerate() {
+                    let xv = $load(px.add(i + u * LANES));
+                    let yv = $load(py.add(i + u * LANES));
+                    *acc = $mul_add(xv, yv, *acc);
+                }
+                i += 4 * LANES;
+            }
+            while i + LANES <= n {
+                acc[0] = $mul_add($load(px.add(i)), $load(py.add(i)), acc[0]);
+                i += LANES;
+            }
+
+            let sum = $add($add(acc[0], acc[1]), $add(acc[2], acc[3]));
+            let mut lanes = [0.0; LANES];
+            $store(lanes.as_mut_ptr(), sum);
+            let mut s = lanes.iter().sum::<$type>();
+            while i < n {
+                s += x[i] * y[i];
+                i += 1;
+            }
+
+            s
+        }
+    };
+}
+
+/// Generates a kernel for `y += alpha * x`.
+macro_rules! simd_axpy {
+    ($name:ident, $type:ty, $feature:literal, $lanes:expr, $set1:ident, $load:ident, $mul_add:expr, $store:ident) => {
+        #[cfg(target_arch = "x86_64")]
+        #[target_feature(enable = $feature)]
+        unsafe fn $name(alpha: $type, x: &[$type], y: &mut [$type]) {
+            const LANES: usize = $lanes;
+            let n = x.len().min(y.len());
+            let (px, py) = (x.as_ptr(), y.as_mut_ptr());
+
+            let av = $set1(alpha);
+            let mut i = 0;
+            while i + LANES <= n {
+                let yv = $mul_add(av, $load(px.add(i)), $load(py.add(i)));
+                $store(py.add(i), yv);
+                i += LANES;
+            }
+            while i < n {
+                y[i] += alpha * x[i];
+                i += 1;
+            }
+        }
+    };
+}
+
+/// Generates a gemm microkernel holding the `MR x nr` tile in `MR * nr / LANES` vector
+/// registers and updating it with one rank one update per step of `kc`.
+macro_rules! simd_microkernel {
+    ($name:ident, $inner:ident, $type:ty, $feature:literal, $lanes:expr, $nr:expr, $zero:ident, $set1:ident, $load:ident, $mul_add:expr, $store:ident) => {
+        #[cfg(target_arch = "x86_64")]
+        fn $name<T: 'static>(kc: usize, a_panel: &[T], b_panel: &[T], ab: &mut [T]) {
+            let a_panel = cast(a_panel).unwrap();
+            let b_panel = cast(b_panel).unwrap();
+            let ab = cast_mut(ab).unwrap();
+            // SAFETY: this kernel is only selected by `microkernel` when the CPU supports the
+            // required target features
+            unsafe { $inner(kc, a_panel, b_panel, ab) }
+        }
+
+        #[cfg(target_arch = "x86_64")]
+        #[target_feature(enable = $feature)]
+        unsafe fn $inner(kc: usize, a_panel: &[$type], b_panel: &[$type], ab: &mut [$type]) {
+            const LANES: usize = $lanes;
+            const VECS: usize = $nr / LANES;
+            assert!(a_panel.len() >= kc * MR && b_panel.len() >= kc * $nr && ab.len() >= MR * $nr);
+            let (pa, pb) = (a_panel.as_ptr(), b_panel.as_ptr());
+
+            let mut acc = [[$zero(); VECS]; MR];
+            for p in 0..kc {
+                let mut b = [$zero(); VECS];
+                for (v, b) in b.iter_mut().enumerate() {
+                    *b = $load(pb.add(p * $nr + v * LANES));
+                }
+                for (i, acc) in acc.iter_mut().enumerate() {
+                    let a = $set1(*pa.add(p * MR + i));
+                    for (acc, &b) in acc.iter_mut().zip(&b) {
+                        *acc = $mul_add(a, b, *acc);
+                    }
+                }
+            }
+
+            let pab = ab.as_mut_ptr();
+            for (i, acc) in acc.iter().enumerate() {
+                for (v, &acc) in acc.iter().enumerate() {
+                    $store(pab.add(i * $nr + v * LANES), acc);
+                }
+            }
+        }
+    };
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+unsafe fn mul_add_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    _mm_add_pd(_mm_mul_pd(a, b), c)
+}
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+unsafe fn mul_add_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    _mm_add_ps(_mm_mul_ps(a, b), c)
+}
+
+simd_dot!(
+    dot_f64_avx2,
+    f64,
+    "avx2,fma",
+    4,
+    _mm256_setzero_pd,
+    _mm256_loadu_pd,
+    _mm256_fmadd_pd,
+    _mm256_add_pd,
+    _mm256_storeu_pd
+);
+simd_dot!(
+    dot_f32_avx2,
+    f32,
+    "avx2,fma",
+    8,
+    _mm256_setzero_ps,
+    _mm256_loadu_ps,
+    _mm256_fmadd_ps,
+    _mm256_add_ps,
+    _mm256_storeu_ps
+);
+simd_dot!(
+    dot_f64_sse2,
+    f64,
+    "sse2",
+    2,
+    _mm_setzero_pd,
+    _mm_loadu_pd,
+    mul_add_pd,
+    _mm_add_pd,
+    _mm_storeu_pd
+);
+simd_dot!(
+    dot_f32_sse2,
+    f32,
+    "sse2",
+    4,
+    _mm_setzero_ps,
+    _mm_loadu_ps,
+    mul_add_ps,
+    _mm_add_ps,
+    _mm_storeu_ps
+);
+
+simd_axpy!(
+    axpy_f64_avx2,
+    f64,
+    "avx2,fma",
+    4,
+    _mm256_set1_pd,
+    _mm256_loadu_pd,
+    _mm256_fmadd_pd,
+    _mm256_storeu_pd
+);
+simd_axpy!(
+    axpy_f32_avx2,
+    f32,
+    "avx2,fma",
+    8,
+    _mm256_set1_ps,
+    _mm256_loadu_ps,
+    _mm256_fmadd_ps,
+    _mm256_storeu_ps
+);
+simd_axpy!(
+    axpy_f64_sse2,
+    f64,
+    "sse2",
+    2,
+    _mm_set1_pd,
+    _mm_loadu_pd,
+    mul_add_pd,
+    _mm_storeu_pd
+);
+simd_axpy!(
+    axpy_f32_sse2,
+    f32,
+    "sse2",
+    4,
+    _mm_set1_ps,
+    _mm_loadu_ps,
+    mul_add_ps,
+    _mm_storeu_ps
+);
+
+simd_microkernel!(
+    microkernel_f64_avx2,
+    microkernel_f64_avx2_inner,
+    f64,
+    "avx2,fma",
+    4,
+    8,
+    _mm256_setzero_pd,
+    _mm256_set1_pd,
+    _mm256_loadu_pd,
+    _mm256_fmadd_pd,
+    _mm256_storeu_pd
+);
+simd_microkernel!(
+    microkernel_f32_avx2,
+    microkernel_f32_avx2_inner,
+    f32,
+    "avx2,fma",
+    8,
+    16,
+    _mm256_setzero_ps,
+    _mm256_set1_ps,
+    _mm256_loadu_ps,
+    _mm256_fmadd_ps,
+    _mm256_storeu_ps
+);
+simd_microkernel!(
+    microkernel_f64_sse2,
+    microkernel_f64_sse2_inner,
+    f64,
+    "sse2",
+    2,
+    8,
+    _mm_setzero_pd,
+    _mm_set1_pd,
+    _mm_loadu_pd,
+    mul_add_pd,
+    _mm_storeu_pd
+);
+simd_microkernel!(
+    microkernel_f32_sse2,
+    microkernel_f32_sse2_inner,
+    f32,
+    "sse2",
+    4,
+    16,
+    _mm_setzero_ps,
+    _mm_set1_ps,
+    _mm_loadu_ps,
+    mul_add_ps,
+    _mm_storeu_ps
+);
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_dot() {
+        for n in [0, 1, 3, 8, 17, 33, 100] {
+            let x: Vec<f64> = (0..n).map(|i| i as f64 / 3.0).collect();
+            let y: Vec<f64> = (0..n).map(|i| 1.0 - i as f64 / 7.0).collect();
+            assert_relative_eq!(dot(&x, &y), dot_scalar(&x, &y), epsilon = 1e-10);
+
+            let x: Vec<f32> = x.iter().map(|&x| x as f32).collect();
+            let y: Vec<f32> = y.iter().map(|&y| y as f32).collect();
+            assert_relative_eq!(dot(&x, &y), dot_scalar(&x, &y), max_relative = 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_axpy() {
+        for n in [0, 1, 5, 16, 31] {
+            let x: Vec<f64> = (0..n).map(|i| i as f64).collect();
+            let mut y: Vec<f64> = (0..n).map(|i| 2.0 * i as f64 + 1.0).collect();
+            axpy(-2.0, &x, &mut y);
+            assert!(y.iter().all(|&y| y == 1.0));
+
+            let x: Vec<f32> = (0..n).map(|i| i as f32).collect();
+            let mut y: Vec<f32> = vec![0.5; n];
+            axpy(0.5, &x, &mut y);
+            for (i, &y) in y.iter().enumerate() {
+                assert_eq!(y, 0.5 + 0.5 * i as f32);
+            }
+        }
+    }
+
+    fn check_microkernel<T>(kernel: Microkernel<T>, kc: usize)
+    where
+        T: RealField + std::fmt::Debug,
+    {
+        let nr = nr::<T>();
+        let mut a = vec![T::zero(); kc * MR];
+        let mut b = vec![T::zero(); kc * nr];
+        let mut x = T::zero();
+        for a in a.iter_mut() {
+            x += T::one();
+            *a = x;
+        }
+        for (j, b) in b.iter_mut().enumerate() {
+            *b = if j % 3 == 0 { T::one() } else { -T::one() };
+        }
+
+        let mut expected = vec![T::zero(); MR * nr];
+        let mut ab = vec![T::zero(); MR * nr];
+        microkernel_scalar(kc, &a, &b, &mut expected);
+        kernel(kc, &a, &b, &mut ab);
+        assert_eq!(ab, expected);
+    }
+
+    #[test]
+    fn test_microkernel() {
+        for kc in [0, 1, 7, 64] {
+            check_microkernel(microkernel::<f64>(), kc);
+            check_microkernel(microkernel::<f32>(), kc);
+
+            #[cfg(target_arch = "x86_64")]
+            {
+                check_microkernel::<f64>(microkernel_f64_sse2::<f64>, kc);
+                check_microkernel::<f32>(microkernel_f32_sse2::<f32>, kc);
+            }
+        }
+    }
+}
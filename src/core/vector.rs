@@ -1,7 +1,15 @@
 use std::default::Default;
-use std::ops::{Add, AddAssign, Index, IndexMut, Sub, SubAssign};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
-#[derive(Debug)]
+use crate::core::error::ShapeError;
+use crate::core::error::ShapeError::DimensionMismatchError;
+use crate::core::gemm::axpy;
+use crate::core::matrix::forward_ref_binop;
+use crate::core::scalar::{ComplexField, One, RealField, Scalar, Zero};
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct Vector<T> {
     pub n: usize,
     pub(crate) data: Vec<T>,
@@ -17,9 +25,7 @@ where
     }
 
     pub fn from_gen(n: usize, gen: fn(usize) -> T) -> Self {
-        let data = (0..n).map(gen).collect();
-
-        Self { n, data }
+        Self::from_fn(n, gen)
     }
 
     pub fn from_vec(data: &[T]) -> Self {
@@ -35,6 +41,51 @@ where
     }
 }
 
+impl<T> Vector<T> {
+    /// Vector whose `i`-th entry is `f(i)`. Unlike `from_gen`, `f` may capture its environment.
+    pub fn from_fn<F>(n: usize, f: F) -> Self
+    where
+        F: FnMut(usize) -> T,
+    {
+        let data = (0..n).map(f).collect();
+
+        Self { n, data }
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+}
+
+impl<T> From<Vec<T>> for Vector<T> {
+    fn from(data: Vec<T>) -> Self {
+        Self {
+            n: data.len(),
+            data,
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for Vector<T> {
+    fn from(data: [T; N]) -> Self {
+        Self::from(Vec::from(data))
+    }
+}
+
+impl<T> From<Vector<T>> for Vec<T> {
+    fn from(v: Vector<T>) -> Self {
+        v.data
+    }
+}
+
 impl<T> Index<usize> for Vector<T> {
     type Output = T;
 
@@ -63,6 +114,39 @@ impl<T> IndexMut<usize> for Vector<T> {
     }
 }
 
+impl<T> Vector<T> {
+    pub(crate) fn check_same_len(&self, other: &Self) -> Result<(), ShapeError> {
+        if self.n != other.n {
+            return Err(DimensionMismatchError {
+                lhs: (self.n, 1),
+                rhs: (other.n, 1),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Computes `self + other`, or fails if the lengths differ.
+    pub fn try_add(&self, other: &Self) -> Result<Self, ShapeError>
+    where
+        T: Add<Output = T> + Clone,
+    {
+        self.check_same_len(other)?;
+
+        Ok(self + other)
+    }
+
+    /// Computes `self - other`, or fails if the lengths differ.
+    pub fn try_sub(&self, other: &Self) -> Result<Self, ShapeError>
+    where
+        T: Sub<Output = T> + Clone,
+    {
+        self.check_same_len(other)?;
+
+        Ok(self - other)
+    }
+}
+
 impl<T> Add for &Vector<T>
 where
     T: Add<Output = T> + Clone,
@@ -151,9 +235,672 @@ where
     }
 }
 
+forward_ref_binop!(impl Add, add for Vector<T>, Vector<T> => Vector<T>);
+forward_ref_binop!(impl Sub, sub for Vector<T>, Vector<T> => Vector<T>);
+
+impl<T> Vector<T> {
+    /// Combines corresponding elements of two vectors of the same length with `f`.
+    fn zip_with<F>(&self, other: &Self, op: &str, f: F) -> Self
+    where
+        F: Fn(&T, &T) -> T,
+    {
+        if self.n != other.n {
+            panic!(
+                "Cannot {} vectors of different shapes: {} and {}",
+                op, self.n, other.n
+            );
+        }
+
+        let data = self
+            .data
+            .iter()
+            .zip(&other.data)
+            .map(|(x, y)| f(x, y))
+            .collect();
+        Self { n: self.n, data }
+    }
+
+    /// Element-wise (Hadamard) product.
+    pub fn hadamard(&self, other: &Self) -> Self
+    where
+        T: Mul<Output = T> + Clone,
+    {
+        self.zip_with(other, "multiply", |x, y| x.clone() * y.clone())
+    }
+
+    /// Element-wise division.
+    pub fn hadamard_div(&self, other: &Self) -> Self
+    where
+        T: Div<Output = T> + Clone,
+    {
+        self.zip_with(other, "divide", |x, y| x.clone() / y.clone())
+    }
+}
+
+impl<T> Mul<T> for &Vector<T>
+where
+    T: Mul<Output = T> + Copy,
+{
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        let data = self.data.iter().map(|&x| x * rhs).collect();
+        Vector { n: self.n, data }
+    }
+}
+
+impl<T> Mul<T> for Vector<T>
+where
+    T: MulAssign + Copy,
+{
+    type Output = Vector<T>;
+
+    fn mul(mut self, rhs: T) -> Self::Output {
+        self *= rhs;
+        self
+    }
+}
+
+impl<T> MulAssign<T> for Vector<T>
+where
+    T: MulAssign + Copy,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.data.iter_mut().for_each(|x| *x *= rhs);
+    }
+}
+
+impl<T> Div<T> for &Vector<T>
+where
+    T: Div<Output = T> + Copy,
+{
+    type Output = Vector<T>;
+
+    fn div(self, rhs: T) -> Self::Output {
+        let data = self.data.iter().map(|&x| x / rhs).collect();
+        Vector { n: self.n, data }
+    }
+}
+
+impl<T> Div<T> for Vector<T>
+where
+    T: DivAssign + Copy,
+{
+    type Output = Vector<T>;
+
+    fn div(mut self, rhs: T) -> Self::Output {
+        self /= rhs;
+        self
+    }
+}
+
+impl<T> DivAssign<T> for Vector<T>
+where
+    T: DivAssign + Copy,
+{
+    fn div_assign(&mut self, rhs: T) {
+        self.data.iter_mut().for_each(|x| *x /= rhs);
+    }
+}
+
+impl<T> Neg for &Vector<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Vector<T>;
+
+    fn neg(self) -> Self::Output {
+        let data = self.data.iter().map(|&x| -x).collect();
+        Vector { n: self.n, data }
+    }
+}
+
+impl<T> Neg for Vector<T>
+where
+    T: Neg<Output = T> + Copy,
+{
+    type Output = Vector<T>;
+
+    fn neg(mut self) -> Self::Output {
+        self.data.iter_mut().for_each(|x| *x = -*x);
+        self
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Zero + Clone,
+{
+    pub fn zeros(n: usize) -> Self {
+        Self::from_fn(n, |_| T::zero())
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: One + Clone,
+{
+    pub fn ones(n: usize) -> Self {
+        Self::from_fn(n, |_| T::one())
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Arange,
+{
+    /// Values `start + k * step` for `k = 0, 1, ...`, up to but excluding `end`. The step may be
+    /// negative, in which case the values decrease down to `end`. For integers, no value past
+    /// `end` is computed, so the range may run up to the limits of the type. For floats, the
+    /// range also ends once `k` is too large to be incremented, after `2^24 + 1` values for
+    /// `f32`. Panics if the step is zero, or if any of the arguments is NaN.
+    pub fn arange(start: T, end: T, step: T) -> Self {
+        Self::from(T::arange(start, end, step))
+    }
+}
+
+/// Scalars that `Vector::arange` can step through.
+pub trait Arange: Scalar + PartialOrd {
+    /// Values of `Vector::arange(start, end, step)`.
+    fn arange(start: Self, end: Self, step: Self) -> Vec<Self>;
+}
+
+impl<T> Arange for T
+where
+    T: RealField,
+{
+    fn arange(start: T, end: T, step: T) -> Vec<T> {
+        if start.partial_cmp(&end).is_none() || step.partial_cmp(&T::zero()).is_none() {
+            panic!(
+                "Cannot build a range from unordered values {:?}, {:?}, {:?}",
+                start, end, step
+            );
+        }
+        if step == T::zero() {
+            panic!("Cannot build a range with a zero step");
+        }
+
+        let increasing = step > T::zero();
+        let (mut data, mut k) = (Vec::new(), T::zero());
+        loop {
+            // The first value is start even if the step is infinite, and 0 * step is NaN
+            let x = if k.is_zero() { start } else { start + k * step };
+            let inside = if increasing { x < end } else { x > end };
+            if !inside {
+                break;
+            }
+            data.push(x);
+
+            let next = k + T::one();
+            if next <= k {
+                break;
+            }
+            k = next;
+        }
+
+        data
+    }
+}
+
+macro_rules! arange_int {
+    ($($type:ty),*) => {
+        $(
+            impl Arange for $type {
+                // Steps from one value to the next rather than computing start + k * step, as k
+                // may not fit in the type even when every value does
+                fn arange(start: $type, end: $type, step: $type) -> Vec<$type> {
+                    if step == 0 {
+                        panic!("Cannot build a range with a zero step");
+                    }
+
+                    let increasing = step > 0;
+                    let (mut data, mut x) = (Vec::new(), start);
+                    while if increasing { x < end } else { x > end } {
+                        data.push(x);
+                        // A value past the limits of the type is also past end
+                        match x.checked_add(step) {
+                            Some(next) => x = next,
+                            None => break,
+                        }
+                    }
+
+                    data
+                }
+            }
+        )*
+    };
+}
+
+arange_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl<T> Vector<T>
+where
+    T: RealField,
+{
+    /// `n` evenly spaced values from `start` to `end`, both included.
+    pub fn linspace(start: T, end: T, n: usize) -> Self {
+        let mut intervals = T::zero();
+        for _ in 1..n {
+            intervals += T::one();
+        }
+        let step = (end - start) / intervals;
+
+        let mut k = T::zero();
+        Self::from_fn(n, |i| {
+            let x = if i + 1 == n { end } else { start + k * step };
+            k += T::one();
+            x
+        })
+    }
+
+    /// Sum of the absolute values, as in BLAS. Equal to `norm1`.
+    pub fn asum(&self) -> T {
+        self.data.iter().map(|x| x.abs()).sum()
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: ComplexField,
+{
+    /// Sum of the absolute values of the entries.
+    pub fn norm1(&self) -> T::Real {
+        self.data.iter().map(|x| x.modulus()).sum()
+    }
+
+    /// Euclidean norm, scaled as in LAPACK's `nrm2` so that it neither overflows nor underflows
+    /// unless the result does.
+    pub fn norm2(&self) -> T::Real {
+        norm2(&self.data)
+    }
+
+    /// Largest absolute value of the entries, zero for an empty vector.
+    pub fn norm_inf(&self) -> T::Real {
+        self.data
+            .iter()
+            .map(|x| x.modulus())
+            .fold(
+                T::Real::zero(),
+                |max, abs| if abs > max { abs } else { max },
+            )
+    }
+
+    /// Unit vector in the direction of `self`. The zero vector is returned unchanged.
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm2();
+        if norm.is_zero() {
+            return self.clone();
+        }
+
+        self / T::from_real(norm)
+    }
+
+    /// Index of the first entry of largest absolute value, as in BLAS, or `None` if the vector
+    /// is empty.
+    pub fn iamax(&self) -> Option<usize> {
+        let mut best: Option<(usize, T::Real)> = None;
+        for (i, x) in self.data.iter().enumerate() {
+            let abs = x.modulus();
+            if best.is_none_or(|(_, max)| abs > max) {
+                best = Some((i, abs));
+            }
+        }
+
+        best.map(|(i, _)| i)
+    }
+}
+
+/// Euclidean norm of `xs`, accumulated as `scale^2 * ssq` so that no square overflows or
+/// underflows.
+pub(crate) fn norm2<'a, T, I>(xs: I) -> T::Real
+where
+    T: ComplexField,
+    I: IntoIterator<Item = &'a T>,
+{
+    let (mut scale, mut ssq) = (T::Real::zero(), T::Real::one());
+    for x in xs {
+        let abs = x.modulus();
+        if abs.is_zero() {
+            continue;
+        }
+        if scale < abs {
+            let r = scale / abs;
+            ssq = T::Real::one() + ssq * r * r;
+            scale = abs;
+        } else {
+            let r = abs / scale;
+            ssq += r * r;
+        }
+    }
+
+    scale * ssq.sqrt()
+}
+
+impl<T> Vector<T>
+where
+    T: Mul<Output = T> + MulAssign + AddAssign + Copy + 'static,
+{
+    /// Computes `self += alpha * x`, see `gemm::axpy`.
+    pub fn axpy(&mut self, alpha: T, x: &Self) {
+        axpy(alpha, x, self);
+    }
+
+    /// Computes `self *= alpha` in place.
+    pub fn scal(&mut self, alpha: T) {
+        *self *= alpha;
+    }
+}
+
+impl<T> Vector<T> {
+    /// Exchanges the entries of `self` and `other`, which must have the same length.
+    pub fn swap(&mut self, other: &mut Self) {
+        if self.n != other.n {
+            panic!(
+                "Vectors must have the same dimensions. Got {} and {}.",
+                self.n, other.n
+            );
+        }
+
+        std::mem::swap(&mut self.data, &mut other.data);
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Scalar,
+{
+    /// Cross product of two vectors of length 3.
+    pub fn cross(&self, other: &Self) -> Self {
+        if self.n != 3 || other.n != 3 {
+            panic!(
+                "Cross product is only defined for vectors of length 3. Got {} and {}.",
+                self.n, other.n
+            );
+        }
+
+        let (x, y) = (&self.data, &other.data);
+        Self::from([
+            x[1] * y[2] - x[2] * y[1],
+            x[2] * y[0] - x[0] * y[2],
+            x[0] * y[1] - x[1] * y[0],
+        ])
+    }
+}
+
+macro_rules! vector_float {
+    ($type:ty) => {
+        impl Vector<$type> {
+            /// Angle in `[0, pi]` between two nonzero vectors, by Kahan's formula
+            /// `2 * atan(|u - v| / |u + v|)` on the unit vectors `u` and `v`, which unlike the
+            /// arc cosine of their dot product stays accurate for nearly parallel vectors. The
+            /// angle with a zero vector is undefined, and is NaN.
+            pub fn angle_between(&self, other: &Self) -> $type {
+                if self.n != other.n {
+                    panic!(
+                        "Vectors must have the same dimensions. Got {} and {}.",
+                        self.n, other.n
+                    );
+                }
+
+                if self.norm2() == 0.0 || other.norm2() == 0.0 {
+                    return <$type>::NAN;
+                }
+
+                let (u, v) = (self.normalize(), other.normalize());
+                2.0 * (&u - &v).norm2().atan2((&u + &v).norm2())
+            }
+        }
+    };
+}
+
+vector_float!(f32);
+vector_float!(f64);
+
 #[cfg(test)]
 mod test {
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use approx::assert_relative_eq;
+
     use super::*;
+    use crate::core::complex::Complex;
+    use crate::core::gemm::dot;
+
+    #[test]
+    fn test_from_fn() {
+        let mut calls = 0;
+        let v = Vector::from_fn(4, |i| {
+            calls += 1;
+            i * i
+        });
+        assert_eq!(calls, 4);
+        assert_eq!(v.as_slice(), &[0, 1, 4, 9]);
+    }
+
+    #[test]
+    fn test_conversions() {
+        let mut v = Vector::from(vec![1.0, 2.0, 3.0]);
+        assert_eq!(v.n, 3);
+
+        v.as_mut_slice()[1] = -2.0;
+        assert_eq!(v[1], -2.0);
+        assert_eq!(Vec::from(v), vec![1.0, -2.0, 3.0]);
+
+        let v = Vector::from([1, 2]);
+        assert_eq!(v.into_vec(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_try_add_sub() {
+        let x = Vector::from([1.0, 2.0]);
+        let y = Vector::from([0.5, 0.5]);
+
+        assert_eq!(x.try_add(&y).unwrap().as_slice(), &[1.5, 2.5]);
+        assert_eq!(x.try_sub(&y).unwrap().as_slice(), &[0.5, 1.5]);
+        assert!(x.try_add(&Vector::from([1.0])).is_err());
+        assert!(x.try_sub(&Vector::from([1.0])).is_err());
+    }
+
+    #[test]
+    fn test_operators() {
+        let x = Vector::from([1.0, 2.0]);
+        let y = Vector::from([0.5, -1.0]);
+
+        assert_eq!(x.clone() + y.clone(), &x + &y);
+        assert_eq!(&x - y.clone(), Vector::from([0.5, 3.0]));
+        assert_eq!(2.0 * &x, Vector::from([2.0, 4.0]));
+        assert_eq!(x.clone() * 2.0, 2.0 * x.clone());
+        assert_eq!(&x / 2.0, Vector::from([0.5, 1.0]));
+        assert_eq!(-&y, Vector::from([-0.5, 1.0]));
+        assert_eq!(-y.clone(), &y * -1.0);
+
+        let mut z = x.clone();
+        z *= 4.0;
+        z /= 2.0;
+        assert_eq!(z, x.clone() / 0.5);
+
+        assert_eq!(x.hadamard(&y), Vector::from([0.5, -2.0]));
+        assert_eq!(x.hadamard_div(&y), Vector::from([2.0, -2.0]));
+    }
+
+    #[test]
+    fn test_constructors() {
+        assert_eq!(Vector::<f64>::zeros(3).as_slice(), &[0.0; 3]);
+        assert_eq!(Vector::<u8>::ones(2).as_slice(), &[1, 1]);
+        assert_eq!(Vector::<i32>::zeros(0).n, 0);
+
+        assert_eq!(Vector::arange(0, 5, 2).as_slice(), &[0, 2, 4]);
+        assert_eq!(Vector::arange(3, -3, -3).as_slice(), &[3, 0]);
+        assert_eq!(Vector::arange(1, 1, 1).n, 0);
+        // Values are computed as start + k * step, so they do not accumulate rounding errors
+        let x = Vector::arange(0.0, 1.0, 0.1);
+        assert_eq!(x.n, 10);
+        assert_eq!(x[9], 9.0 * 0.1);
+
+        let x = Vector::linspace(-1.0, 1.0, 5);
+        assert_eq!(x.as_slice(), &[-1.0, -0.5, 0.0, 0.5, 1.0]);
+        let x = Vector::linspace(0.0f32, 1.0, 7);
+        assert_eq!((x[0], x[6]), (0.0, 1.0));
+        assert_relative_eq!(x[3], 0.5);
+        assert_eq!(Vector::linspace(2.0, 3.0, 1).as_slice(), &[3.0]);
+        assert_eq!(Vector::<f64>::linspace(2.0, 3.0, 0).n, 0);
+    }
 
-    // TODO
+    #[test]
+    #[should_panic]
+    fn test_arange_zero_step() {
+        Vector::arange(0, 1, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_arange_nan() {
+        Vector::arange(0.0, f64::NAN, 1.0);
+    }
+
+    #[test]
+    fn test_arange_long() {
+        // 1e8 values cannot all be told apart in f32, where 2^24 + 1 rounds down to 2^24
+        let x = Vector::arange(0f32, 1e8, 1.0);
+        assert_eq!(x.n, (1 << 24) + 1);
+        assert_eq!(x[x.n - 1], 16777216.0);
+
+        assert_eq!(Vector::arange(0.0, 1.0, f64::INFINITY).as_slice(), &[0.0]);
+    }
+
+    #[test]
+    fn test_arange_int_limits() {
+        let x = Vector::<i32>::arange(i32::MAX - 5, i32::MAX, 2);
+        assert_eq!(x.as_slice(), &[i32::MAX - 5, i32::MAX - 3, i32::MAX - 1]);
+        let x = Vector::<i32>::arange(i32::MIN + 4, i32::MIN, -3);
+        assert_eq!(x.as_slice(), &[i32::MIN + 4, i32::MIN + 1]);
+
+        // More values than i8 can count
+        let x = Vector::<i8>::arange(-100, 100, 1);
+        assert_eq!(x.n, 200);
+        assert_eq!((x[0], x[199]), (-100, 99));
+        assert_eq!(
+            Vector::<i8>::arange(-128, 127, 100).as_slice(),
+            &[-128, -28, 72]
+        );
+        assert_eq!(
+            Vector::<i8>::arange(127, -128, -127).as_slice(),
+            &[127, 0, -127]
+        );
+
+        assert_eq!(
+            Vector::<u8>::arange(250, 255, 2).as_slice(),
+            &[250, 252, 254]
+        );
+        assert_eq!(Vector::<u8>::arange(0, 255, 1).n, 255);
+        assert_eq!(Vector::<u8>::arange(200, 255, 100).as_slice(), &[200]);
+    }
+
+    #[test]
+    fn test_norms() {
+        let x = Vector::from([3.0, -4.0, 0.0]);
+        assert_eq!(x.norm1(), 7.0);
+        assert_eq!(x.asum(), 7.0);
+        assert_eq!(x.norm2(), 5.0);
+        assert_eq!(x.norm_inf(), 4.0);
+        assert_eq!(x.iamax(), Some(1));
+
+        let empty = Vector::<f64>::zeros(0);
+        assert_eq!(
+            (empty.norm1(), empty.norm2(), empty.norm_inf()),
+            (0.0, 0.0, 0.0)
+        );
+        assert_eq!(empty.iamax(), None);
+        // Ties go to the first index
+        assert_eq!(Vector::from([1.0, -2.0, 2.0]).iamax(), Some(1));
+
+        // Squaring these entries would overflow or underflow
+        assert_relative_eq!(Vector::from([3e200, 4e200]).norm2(), 5e200);
+        assert_relative_eq!(Vector::from([3e-200, -4e-200]).norm2(), 5e-200);
+        assert_relative_eq!(Vector::from([3e30f32, 4e30]).norm2(), 5e30);
+
+        let z = Vector::from([Complex::new(3.0, 4.0), Complex::new(0.0, -12.0)]);
+        assert_eq!(z.norm1(), 17.0);
+        assert_eq!(z.norm2(), 13.0);
+        assert_eq!(z.norm_inf(), 12.0);
+        assert_eq!(z.iamax(), Some(1));
+    }
+
+    #[test]
+    fn test_normalize() {
+        let x = Vector::from([3.0, 0.0, -4.0]).normalize();
+        assert_eq!(x.as_slice(), &[0.6, 0.0, -0.8]);
+        assert_eq!(x.norm2(), 1.0);
+        assert_eq!(Vector::<f64>::zeros(2).normalize(), Vector::zeros(2));
+
+        let z = Vector::from([Complex::new(0.0, 2.0)]).normalize();
+        assert_eq!(z[0], Complex::new(0.0, 1.0));
+    }
+
+    #[test]
+    fn test_blas_level1() {
+        let mut y = Vector::from([1.0, 2.0, 3.0]);
+        y.axpy(2.0, &Vector::from([1.0, 0.0, -1.0]));
+        assert_eq!(y.as_slice(), &[3.0, 2.0, 1.0]);
+
+        y.scal(0.5);
+        assert_eq!(y.as_slice(), &[1.5, 1.0, 0.5]);
+
+        let mut x = Vector::from([7.0, 8.0, 9.0]);
+        x.swap(&mut y);
+        assert_eq!(x.as_slice(), &[1.5, 1.0, 0.5]);
+        assert_eq!(y.as_slice(), &[7.0, 8.0, 9.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_swap_dims() {
+        Vector::from([1, 2]).swap(&mut Vector::from([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_cross() {
+        let (e1, e2) = (Vector::from([1, 0, 0]), Vector::from([0, 1, 0]));
+        assert_eq!(e1.cross(&e2), Vector::from([0, 0, 1]));
+        assert_eq!(e2.cross(&e1), Vector::from([0, 0, -1]));
+
+        let x = Vector::from([1.0, 2.0, 3.0]);
+        let y = Vector::from([-2.0, 0.5, 4.0]);
+        let z = x.cross(&y);
+        assert_eq!(z.as_slice(), &[6.5, -10.0, 4.5]);
+        assert_eq!(dot(&z, &x), 0.0);
+        assert_eq!(dot(&z, &y), 0.0);
+        assert_eq!(x.cross(&x), Vector::zeros(3));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_cross_dims() {
+        Vector::from([1.0, 2.0]).cross(&Vector::from([3.0, 4.0]));
+    }
+
+    #[test]
+    fn test_angle_between() {
+        let x: Vector<f64> = Vector::from([1.0, 0.0]);
+        assert_eq!(x.angle_between(&x), 0.0);
+        assert_relative_eq!(x.angle_between(&Vector::from([0.0, 3.0])), FRAC_PI_2);
+        assert_relative_eq!(x.angle_between(&Vector::from([-2.0, 0.0])), PI);
+        assert_relative_eq!(x.angle_between(&Vector::from([1.0, 1.0])), FRAC_PI_4);
+
+        // acos(cos(1e-10)) rounds to zero, but the angle is still recovered
+        let y = Vector::from([1.0, 1e-10]);
+        assert_relative_eq!(x.angle_between(&y), 1e-10, max_relative = 1e-12);
+        assert_relative_eq!(
+            Vector::from([1.0f32, 1.0, 0.0]).angle_between(&Vector::from([0.0, 1.0, 1.0])),
+            std::f32::consts::FRAC_PI_3
+        );
+
+        let zero = Vector::from([0.0, 0.0]);
+        assert!(x.angle_between(&zero).is_nan());
+        assert!(zero.angle_between(&x).is_nan());
+        assert!(zero.angle_between(&zero).is_nan());
+        assert!(Vector::<f32>::zeros(3)
+            .angle_between(&Vector::ones(3))
+            .is_nan());
+    }
 }
@@ -0,0 +1,726 @@
+//! Borrowed, strided views into matrices.
+//!
+//! A view addresses element `(i, j)` at `i * row_stride + j * col_stride` from its origin, so
+//! sub-blocks, rows, columns and transposes of a matrix are all views of the same storage and
+//! are obtained without copying. Mutable views handed out by the `split_at_*` methods never
+//! overlap, even when their elements interleave in memory, so they can be written to
+//! independently.
+
+use std::marker::PhantomData;
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+
+use crate::core::matrix::{Layout, Matrix};
+use crate::core::simd;
+
+/// Read-only view of a matrix stored with arbitrary row and column strides.
+///
+/// The shape is only readable from outside the crate, since element access relies on it to stay
+/// within the borrowed storage:
+///
+/// ```compile_fail
+/// use numerics::prelude::*;
+///
+/// let m = matrix![1, 2; 3, 4];
+/// let mut v = m.row(1);
+/// v.n_rows = 1000;
+/// ```
+#[derive(Debug)]
+pub struct MatrixView<'a, T> {
+    ptr: *const T,
+    pub(crate) n_rows: usize,
+    pub(crate) n_cols: usize,
+    pub(crate) row_stride: usize,
+    pub(crate) col_stride: usize,
+    marker: PhantomData<&'a T>,
+}
+
+/// Mutable view of a matrix stored with arbitrary row and column strides. Like [`MatrixView`],
+/// its shape cannot be changed from outside the crate.
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    ptr: *mut T,
+    pub(crate) n_rows: usize,
+    pub(crate) n_cols: usize,
+    pub(crate) row_stride: usize,
+    pub(crate) col_stride: usize,
+    marker: PhantomData<&'a mut T>,
+}
+
+// SAFETY: views behave like `&'a T` and `&'a mut T` respectively
+unsafe impl<T: Sync> Send for MatrixView<'_, T> {}
+unsafe impl<T: Sync> Sync for MatrixView<'_, T> {}
+unsafe impl<T: Send> Send for MatrixViewMut<'_, T> {}
+unsafe impl<T: Sync> Sync for MatrixViewMut<'_, T> {}
+
+impl<T> Clone for MatrixView<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for MatrixView<'_, T> {}
+
+/// Types that can be borrowed as a [`MatrixView`].
+pub trait AsView {
+    type Elem;
+
+    fn as_view(&self) -> MatrixView<'_, Self::Elem>;
+}
+
+/// Types that can be borrowed as a [`MatrixViewMut`].
+pub trait AsViewMut: AsView {
+    fn as_view_mut(&mut self) -> MatrixViewMut<'_, Self::Elem>;
+}
+
+/// Resolves `range` against a dimension of length `len`, panicking if it is out of bounds.
+fn resolve(range: impl RangeBounds<usize>, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&s) => s,
+        Bound::Excluded(&s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&e) => e + 1,
+        Bound::Excluded(&e) => e,
+        Bound::Unbounded => len,
+    };
+    if start > end || end > len {
+        panic!(
+            "Range {:?} out of bounds for dimension of size {}",
+            start..end,
+            len
+        );
+    }
+
+    start..end
+}
+
+/// Whether every element of an `n_rows x n_cols` matrix with the given strides lies within
+/// `len` elements of storage.
+fn fits(len: usize, n_rows: usize, n_cols: usize, row_stride: usize, col_stride: usize) -> bool {
+    n_rows == 0 || n_cols == 0 || (n_rows - 1) * row_stride + (n_cols - 1) * col_stride < len
+}
+
+fn check_index(index: (usize, usize), n_rows: usize, n_cols: usize) {
+    let (i, j) = index;
+    if i >= n_rows || j >= n_cols {
+        panic!(
+            "Index {:?} out of bounds for matrix of size {:?}",
+            index,
+            (n_rows, n_cols)
+        );
+    }
+}
+
+impl<'a, T> MatrixView<'a, T> {
+    /// View of an `n_rows x n_cols` matrix stored in `data` with the given strides, which must
+    /// fit in `data`.
+    pub(crate) fn new(
+        data: &'a [T],
+        n_rows: usize,
+        n_cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Self {
+        assert!(fits(data.len(), n_rows, n_cols, row_stride, col_stride));
+        Self {
+            ptr: data.as_ptr(),
+            n_rows,
+            n_cols,
+            row_stride,
+            col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// View of a dense row-major `n_rows x n_cols` matrix.
+    pub(crate) fn row_major(data: &'a [T], n_rows: usize, n_cols: usize) -> Self {
+        Self::new(data, n_rows, n_cols, n_cols, 1)
+    }
+
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    pub fn n_cols(&self) -> usize {
+        self.n_cols
+    }
+
+    /// View of the `n_rows x n_cols` block starting at `(i, j)`.
+    pub(crate) fn block(self, i: usize, j: usize, n_rows: usize, n_cols: usize) -> Self {
+        assert!(i + n_rows <= self.n_rows && j + n_cols <= self.n_cols);
+        Self {
+            // Empty blocks may start one past the end of the storage, so the offset must not
+            // be assumed in bounds
+            ptr: self
+                .ptr
+                .wrapping_add(i * self.row_stride + j * self.col_stride),
+            n_rows,
+            n_cols,
+            ..self
+        }
+    }
+
+    /// View of the rows in `rows` and columns in `cols`.
+    pub fn view(&self, rows: impl RangeBounds<usize>, cols: impl RangeBounds<usize>) -> Self {
+        let (rows, cols) = (resolve(rows, self.n_rows), resolve(cols, self.n_cols));
+        self.block(rows.start, cols.start, rows.len(), cols.len())
+    }
+
+    /// View of row `i` as a `1 x n_cols` matrix.
+    pub fn row(&self, i: usize) -> Self {
+        check_index((i, 0), self.n_rows, 1);
+        self.block(i, 0, 1, self.n_cols)
+    }
+
+    /// View of column `j` as an `n_rows x 1` matrix.
+    pub fn col(&self, j: usize) -> Self {
+        check_index((0, j), 1, self.n_cols);
+        self.block(0, j, self.n_rows, 1)
+    }
+
+    /// Splits into the rows before `i` and the rows from `i` on.
+    pub fn split_at_row(&self, i: usize) -> (Self, Self) {
+        (self.view(..i, ..), self.view(i.., ..))
+    }
+
+    /// Splits into the columns before `j` and the columns from `j` on.
+    pub fn split_at_col(&self, j: usize) -> (Self, Self) {
+        (self.view(.., ..j), self.view(.., j..))
+    }
+
+    /// View of the transpose, obtained by swapping the shape and the strides.
+    pub fn t(&self) -> Self {
+        Self {
+            n_rows: self.n_cols,
+            n_cols: self.n_rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            ..*self
+        }
+    }
+
+    /// Reference to element `(i, j)` with the lifetime of the underlying storage.
+    pub fn get(&self, i: usize, j: usize) -> &'a T {
+        check_index((i, j), self.n_rows, self.n_cols);
+        // SAFETY: `(i, j)` is within the shape, and every such element is in bounds of the
+        // borrowed storage
+        unsafe { &*self.ptr.add(i * self.row_stride + j * self.col_stride) }
+    }
+
+    #[inline(always)]
+    pub(crate) fn at(&self, i: usize, j: usize) -> T
+    where
+        T: Copy,
+    {
+        *self.get(i, j)
+    }
+
+    /// Element `(i, j)` without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `(i, j)` must be within the shape of the view.
+    #[inline(always)]
+    pub(crate) unsafe fn at_unchecked(&self, i: usize, j: usize) -> T
+    where
+        T: Copy,
+    {
+        debug_assert!(i < self.n_rows && j < self.n_cols);
+        *self.ptr.add(i * self.row_stride + j * self.col_stride)
+    }
+
+    /// Copies the viewed elements into a new row-major matrix.
+    pub fn to_matrix(&self) -> Matrix<T>
+    where
+        T: Copy,
+    {
+        let mut data = Vec::with_capacity(self.n_rows * self.n_cols);
+        for i in 0..self.n_rows {
+            for j in 0..self.n_cols {
+                data.push(self.at(i, j));
+            }
+        }
+
+        Matrix {
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+            data,
+            layout: Layout::RowMajor,
+        }
+    }
+
+    /// Copies the transpose into a new matrix. Use `t` for a view of the transpose instead.
+    pub fn transpose(&self) -> Matrix<T>
+    where
+        T: Copy,
+    {
+        self.t().to_matrix()
+    }
+
+    /// Reinterprets the view as a view of `F` if `T` is `F`.
+    pub(crate) fn cast<F: 'static>(self) -> Option<MatrixView<'a, F>>
+    where
+        T: 'static,
+    {
+        simd::is::<T, F>().then_some(MatrixView {
+            ptr: self.ptr as *const F,
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            marker: PhantomData,
+        })
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T> {
+    /// View of an `n_rows x n_cols` matrix stored in `data` with the given strides, which must
+    /// fit in `data` and map distinct elements to distinct locations.
+    pub(crate) fn new(
+        data: &'a mut [T],
+        n_rows: usize,
+        n_cols: usize,
+        row_stride: usize,
+        col_stride: usize,
+    ) -> Self {
+        assert!(fits(data.len(), n_rows, n_cols, row_stride, col_stride));
+        Self {
+            ptr: data.as_mut_ptr(),
+            n_rows,
+            n_cols,
+            row_stride,
+            col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// View of a dense row-major `n_rows x n_cols` matrix.
+    pub(crate) fn row_major(data: &'a mut [T], n_rows: usize, n_cols: usize) -> Self {
+        Self::new(data, n_rows, n_cols, n_cols, 1)
+    }
+
+    pub fn n_rows(&self) -> usize {
+        self.n_rows
+    }
+
+    pub fn n_cols(&self) -> usize {
+        self.n_cols
+    }
+
+    /// Consumes the view, returning the `n_rows x n_cols` block starting at `(i, j)`.
+    fn into_block(self, i: usize, j: usize, n_rows: usize, n_cols: usize) -> Self {
+        assert!(i + n_rows <= self.n_rows && j + n_cols <= self.n_cols);
+        Self {
+            ptr: self
+                .ptr
+                .wrapping_add(i * self.row_stride + j * self.col_stride),
+            n_rows,
+            n_cols,
+            ..self
+        }
+    }
+
+    /// View of the `n_rows x n_cols` block starting at `(i, j)`.
+    pub(crate) fn block_mut(
+        &mut self,
+        i: usize,
+        j: usize,
+        n_rows: usize,
+        n_cols: usize,
+    ) -> MatrixViewMut<'_, T> {
+        self.reborrow().into_block(i, j, n_rows, n_cols)
+    }
+
+    /// Shorter-lived mutable view of the same elements, leaving `self` usable afterwards.
+    pub fn reborrow(&mut self) -> MatrixViewMut<'_, T> {
+        MatrixViewMut {
+            ptr: self.ptr,
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// Mutable view of the rows in `rows` and columns in `cols`.
+    pub fn view_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixViewMut<'_, T> {
+        let (rows, cols) = (resolve(rows, self.n_rows), resolve(cols, self.n_cols));
+        self.block_mut(rows.start, cols.start, rows.len(), cols.len())
+    }
+
+    /// Mutable view of row `i` as a `1 x n_cols` matrix.
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, T> {
+        check_index((i, 0), self.n_rows, 1);
+        let n_cols = self.n_cols;
+        self.block_mut(i, 0, 1, n_cols)
+    }
+
+    /// Mutable view of column `j` as an `n_rows x 1` matrix.
+    pub fn col_mut(&mut self, j: usize) -> MatrixViewMut<'_, T> {
+        check_index((0, j), 1, self.n_cols);
+        let n_rows = self.n_rows;
+        self.block_mut(0, j, n_rows, 1)
+    }
+
+    /// Splits into the rows before `i` and the rows from `i` on.
+    pub fn split_at_row_mut(self, i: usize) -> (Self, Self) {
+        let (rows, n_cols) = (resolve(..i, self.n_rows), self.n_cols);
+        let rest = self.n_rows - rows.end;
+        // SAFETY: the two halves cover disjoint sets of rows
+        let other = unsafe { self.alias() };
+        (
+            self.into_block(0, 0, rows.end, n_cols),
+            other.into_block(rows.end, 0, rest, n_cols),
+        )
+    }
+
+    /// Splits into the columns before `j` and the columns from `j` on.
+    pub fn split_at_col_mut(self, j: usize) -> (Self, Self) {
+        let (cols, n_rows) = (resolve(..j, self.n_cols), self.n_rows);
+        let rest = self.n_cols - cols.end;
+        // SAFETY: the two halves cover disjoint sets of columns
+        let other = unsafe { self.alias() };
+        (
+            self.into_block(0, 0, n_rows, cols.end),
+            other.into_block(0, cols.end, n_rows, rest),
+        )
+    }
+
+    /// Second view of the same elements.
+    ///
+    /// # Safety
+    ///
+    /// The caller must make sure the two views are never used to access the same element.
+    unsafe fn alias(&self) -> Self {
+        Self {
+            ptr: self.ptr,
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            marker: PhantomData,
+        }
+    }
+
+    /// Mutable view of the transpose, obtained by swapping the shape and the strides.
+    pub fn t_mut(self) -> Self {
+        Self {
+            n_rows: self.n_cols,
+            n_cols: self.n_rows,
+            row_stride: self.col_stride,
+            col_stride: self.row_stride,
+            ..self
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn at_mut(&mut self, i: usize, j: usize) -> &mut T {
+        check_index((i, j), self.n_rows, self.n_cols);
+        // SAFETY: `(i, j)` is within the shape, so the element is in bounds and only reachable
+        // through this view
+        unsafe { &mut *self.ptr.add(i * self.row_stride + j * self.col_stride) }
+    }
+
+    /// Mutable reference to element `(i, j)` without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// `(i, j)` must be within the shape of the view.
+    #[inline(always)]
+    pub(crate) unsafe fn at_unchecked_mut(&mut self, i: usize, j: usize) -> &mut T {
+        debug_assert!(i < self.n_rows && j < self.n_cols);
+        &mut *self.ptr.add(i * self.row_stride + j * self.col_stride)
+    }
+
+    /// Copies the elements of `src`, which must have the same shape, into the view.
+    pub fn copy_from<A>(&mut self, src: &A)
+    where
+        A: AsView<Elem = T> + ?Sized,
+        T: Copy,
+    {
+        let src = src.as_view();
+        if src.n_rows != self.n_rows || src.n_cols != self.n_cols {
+            panic!(
+                "Cannot copy matrix of size {:?} into view of size {:?}",
+                (src.n_rows, src.n_cols),
+                (self.n_rows, self.n_cols)
+            );
+        }
+
+        for i in 0..self.n_rows {
+            for j in 0..self.n_cols {
+                *self.at_mut(i, j) = src.at(i, j);
+            }
+        }
+    }
+
+    /// Sets every element of the view to `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Copy,
+    {
+        for i in 0..self.n_rows {
+            for j in 0..self.n_cols {
+                *self.at_mut(i, j) = value;
+            }
+        }
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixView<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.get(index.0, index.1)
+    }
+}
+
+impl<T> Index<(usize, usize)> for MatrixViewMut<'_, T> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.as_view().get(index.0, index.1)
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for MatrixViewMut<'_, T> {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.at_mut(index.0, index.1)
+    }
+}
+
+impl<T> AsView for Matrix<T> {
+    type Elem = T;
+
+    fn as_view(&self) -> MatrixView<'_, T> {
+        let (row_stride, col_stride) = self.strides();
+        MatrixView::new(&self.data, self.n_rows, self.n_cols, row_stride, col_stride)
+    }
+}
+
+impl<T> AsViewMut for Matrix<T> {
+    fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        let (row_stride, col_stride) = self.strides();
+        MatrixViewMut::new(
+            &mut self.data,
+            self.n_rows,
+            self.n_cols,
+            row_stride,
+            col_stride,
+        )
+    }
+}
+
+impl<T> AsView for MatrixView<'_, T> {
+    type Elem = T;
+
+    fn as_view(&self) -> MatrixView<'_, T> {
+        *self
+    }
+}
+
+impl<T> AsView for MatrixViewMut<'_, T> {
+    type Elem = T;
+
+    fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            ptr: self.ptr,
+            n_rows: self.n_rows,
+            n_cols: self.n_cols,
+            row_stride: self.row_stride,
+            col_stride: self.col_stride,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> AsViewMut for MatrixViewMut<'_, T> {
+    fn as_view_mut(&mut self) -> MatrixViewMut<'_, T> {
+        self.reborrow()
+    }
+}
+
+impl<A> AsView for &A
+where
+    A: AsView + ?Sized,
+{
+    type Elem = A::Elem;
+
+    fn as_view(&self) -> MatrixView<'_, A::Elem> {
+        (**self).as_view()
+    }
+}
+
+impl<T> Matrix<T> {
+    /// View of the rows in `rows` and columns in `cols`, e.g. `m.view(1..3, ..)`.
+    pub fn view(
+        &self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixView<'_, T> {
+        self.as_view().view(rows, cols)
+    }
+
+    /// Mutable view of the rows in `rows` and columns in `cols`.
+    pub fn view_mut(
+        &mut self,
+        rows: impl RangeBounds<usize>,
+        cols: impl RangeBounds<usize>,
+    ) -> MatrixViewMut<'_, T> {
+        let (rows, cols) = (resolve(rows, self.n_rows), resolve(cols, self.n_cols));
+        self.as_view_mut()
+            .into_block(rows.start, cols.start, rows.len(), cols.len())
+    }
+
+    /// View of the transpose, without copying. Use `transpose` for an owned copy instead.
+    pub fn t(&self) -> MatrixView<'_, T> {
+        self.as_view().t()
+    }
+
+    /// View of row `i` as a `1 x n_cols` matrix.
+    pub fn row(&self, i: usize) -> MatrixView<'_, T> {
+        self.as_view().row(i)
+    }
+
+    /// View of column `j` as an `n_rows x 1` matrix.
+    pub fn col(&self, j: usize) -> MatrixView<'_, T> {
+        self.as_view().col(j)
+    }
+
+    /// Mutable view of row `i` as a `1 x n_cols` matrix.
+    pub fn row_mut(&mut self, i: usize) -> MatrixViewMut<'_, T> {
+        self.view_mut(i..=i, ..)
+    }
+
+    /// Mutable view of column `j` as an `n_rows x 1` matrix.
+    pub fn col_mut(&mut self, j: usize) -> MatrixViewMut<'_, T> {
+        self.view_mut(.., j..=j)
+    }
+
+    /// Splits into views of the rows before `i` and the rows from `i` on.
+    pub fn split_at_row(&self, i: usize) -> (MatrixView<'_, T>, MatrixView<'_, T>) {
+        self.as_view().split_at_row(i)
+    }
+
+    /// Splits into views of the columns before `j` and the columns from `j` on.
+    pub fn split_at_col(&self, j: usize) -> (MatrixView<'_, T>, MatrixView<'_, T>) {
+        self.as_view().split_at_col(j)
+    }
+
+    /// Splits into mutable views of the rows before `i` and the rows from `i` on.
+    pub fn split_at_row_mut(&mut self, i: usize) -> (MatrixViewMut<'_, T>, MatrixViewMut<'_, T>) {
+        self.as_view_mut().split_at_row_mut(i)
+    }
+
+    /// Splits into mutable views of the columns before `j` and the columns from `j` on.
+    pub fn split_at_col_mut(&mut self, j: usize) -> (MatrixViewMut<'_, T>, MatrixViewMut<'_, T>) {
+        self.as_view_mut().split_at_col_mut(j)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::matrix;
+
+    #[test]
+    fn test_view() {
+        let m = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+
+        let v = m.view(1.., ..2);
+        assert_eq!((v.n_rows(), v.n_cols()), (2, 2));
+        assert_eq!(v[(0, 0)], 4);
+        assert_eq!(v[(1, 1)], 8);
+
+        let w = v.view(1..2, 1..=1);
+        assert_eq!((w.n_rows, w.n_cols), (1, 1));
+        assert_eq!(w[(0, 0)], 8);
+
+        let empty = m.view(3.., ..);
+        assert_eq!((empty.n_rows, empty.n_cols), (0, 3));
+        assert_eq!(empty.to_matrix().data, Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_row_col() {
+        let m = matrix![1, 2, 3; 4, 5, 6];
+
+        assert_eq!(m.row(1).to_matrix().data, vec![4, 5, 6]);
+        assert_eq!(m.col(2).to_matrix().data, vec![3, 6]);
+        assert_eq!(m.col(1).t().to_matrix().data, vec![2, 5]);
+    }
+
+    #[test]
+    fn test_transpose() {
+        let m = matrix![1, 2, 3; 4, 5, 6];
+
+        let t = m.view(.., 1..).t();
+        assert_eq!((t.n_rows, t.n_cols), (2, 2));
+        assert_eq!(t[(1, 0)], 3);
+        assert_eq!(t.transpose().data, vec![2, 3, 5, 6]);
+    }
+
+    #[test]
+    fn test_split() {
+        let m = matrix![1, 2, 3; 4, 5, 6; 7, 8, 9];
+
+        let (top, bottom) = m.split_at_row(1);
+        assert_eq!(top.to_matrix().data, vec![1, 2, 3]);
+        assert_eq!(bottom.to_matrix().data, vec![4, 5, 6, 7, 8, 9]);
+
+        let (left, right) = m.split_at_col(3);
+        assert_eq!(left.to_matrix().data, m.data);
+        assert_eq!((right.n_rows, right.n_cols), (3, 0));
+    }
+
+    #[test]
+    fn test_split_mut() {
+        let mut m = matrix![1, 2, 3; 4, 5, 6];
+
+        let (mut left, mut right) = m.split_at_col_mut(1);
+        left.fill(0);
+        right.col_mut(1)[(1, 0)] = -6;
+        right[(0, 0)] = -2;
+        assert_eq!(m.data, vec![0, -2, 3, 0, 5, -6]);
+
+        let (top, mut bottom) = m.split_at_row_mut(1);
+        bottom.copy_from(&top);
+        assert_eq!(m.data, vec![0, -2, 3, 0, -2, 3]);
+
+        m.view_mut(.., 2..).t_mut().copy_from(&matrix![7, 8]);
+        assert_eq!(m.row(1).to_matrix().data, vec![0, -2, 8]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_view_out_of_bounds() {
+        let m = matrix![1, 2; 3, 4];
+
+        m.view(1..3, ..);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_index_out_of_bounds() {
+        let m = matrix![1, 2; 3, 4];
+
+        let _ = m.view(.., 1..)[(0, 1)];
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_view_past_storage() {
+        // The last row ends the storage, so a second row of it would be out of bounds
+        let m = matrix![1, 2; 3, 4];
+        let v = m.row(1);
+        assert_eq!((v.n_rows(), v.n_cols()), (1, 2));
+
+        v.get(1, 0);
+    }
+}
@@ -1,50 +1,158 @@
-use crate::core::error::CholDecompositionError;
 use crate::core::error::CholDecompositionError::{NotPositiveDefiniteError, NotSymmetricError};
+use crate::core::error::ShapeError::DimensionMismatchError;
+use crate::core::error::{CholDecompositionError, ShapeError};
 use crate::core::matrix::*;
+use crate::core::scalar::{ComplexField, One, RealField, Zero};
+use crate::core::vector::Vector;
+use crate::core::view::MatrixView;
+use crate::linalg::solve::{bsub, fsub};
 
-pub(crate) trait Cholesky<T> {
-    fn chol(&self) -> Result<Matrix<T>, CholDecompositionError>;
+/// Cholesky decomposition `A = L * L^H` of a Hermitian (for real matrices, symmetric) positive
+/// definite matrix, where `L` is lower triangular with a positive real diagonal.
+#[derive(Debug)]
+pub struct CholeskyFactorization<T> {
+    pub(crate) l: Matrix<T>,
+    pub(crate) lt: Matrix<T>,
 }
 
-#[macro_export]
-macro_rules! impl_cholesky {
-    ($type:ty) => {
-        impl Cholesky<$type> for Matrix<$type> {
-            fn chol(&self) -> Result<Matrix<$type>, CholDecompositionError> {
-                if !self.is_symmetric() {
-                    return Err(NotSymmetricError);
-                }
+pub trait Cholesky<T> {
+    fn chol(&self) -> Result<CholeskyFactorization<T>, CholDecompositionError>;
+}
+
+impl<T> CholeskyFactorization<T> {
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+}
+
+impl<T> CholeskyFactorization<T>
+where
+    T: ComplexField,
+{
+    /// Solves `A x = rhs`. Panics if the length of `rhs` does not match the size of `A`.
+    pub fn solve(&self, rhs: &Vector<T>) -> Vector<T> {
+        if self.l.n_rows != rhs.n {
+            panic!(
+                "Linear system with incompatible dimensions: {:?} and {:?}",
+                (self.l.n_rows, self.l.n_cols),
+                rhs.n
+            );
+        }
+
+        // L y = b, then L^H x = y
+        let y = fsub(&self.l, rhs);
+        bsub(&self.lt, &y)
+    }
 
-                let n = self.n_rows;
-                let mut chol_l = Matrix::new(n, n);
-                // assumes A is square and positive semi-definite
-                for i in 0..n {
-                    let mut diag = self[(i, i)];
+    /// Solves `A x = rhs`, or fails if the length of `rhs` does not match the size of `A`.
+    pub fn try_solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, ShapeError> {
+        if self.l.n_rows != rhs.n {
+            return Err(DimensionMismatchError {
+                lhs: (self.l.n_rows, self.l.n_cols),
+                rhs: (rhs.n, 1),
+            });
+        }
+
+        Ok(self.solve(rhs))
+    }
+
+    /// Solves `A X = rhs` for every column of `rhs`.
+    pub fn solve_matrix(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        let mut x = Matrix::new(rhs.n_rows, rhs.n_cols);
+        let mut b = Vector::new(rhs.n_rows);
+        for c in 0..rhs.n_cols {
+            for i in 0..rhs.n_rows {
+                b[i] = rhs[(i, c)];
+            }
+            let xc = self.solve(&b);
+            for i in 0..rhs.n_rows {
+                x[(i, c)] = xc[i];
+            }
+        }
+
+        x
+    }
+
+    pub fn det(&self) -> T {
+        let mut det = T::one();
+        for i in 0..self.l.n_rows {
+            det *= self.l[(i, i)] * self.l[(i, i)];
+        }
+
+        det
+    }
+
+    /// Natural logarithm of the determinant, which stays finite where `det` would overflow or
+    /// underflow. The determinant of a positive definite matrix is always positive.
+    pub fn log_det(&self) -> T::Real {
+        let mut log_det = T::Real::zero();
+        for i in 0..self.l.n_rows {
+            log_det += self.l[(i, i)].re().ln();
+        }
+
+        log_det + log_det
+    }
+
+    pub fn inverse(&self) -> Matrix<T> {
+        let n = self.l.n_rows;
+        let mut eye = Matrix::new(n, n);
+        for i in 0..n {
+            eye[(i, i)] = T::one();
+        }
+
+        self.solve_matrix(&eye)
+    }
+}
+
+impl<T> Cholesky<T> for Matrix<T>
+where
+    T: ComplexField,
+{
+    fn chol(&self) -> Result<CholeskyFactorization<T>, CholDecompositionError> {
+        if !self.is_hermitian() {
+            return Err(NotSymmetricError);
+        }
+
+        let n = self.n_rows;
+        let mut chol_l = Matrix::<T>::new(n, n);
+        for i in 0..n {
+            let mut diag = self[(i, i)].re();
+            for k in 0..i {
+                diag -= chol_l[(i, k)].modulus_sqr();
+            }
+            // Also rejects a NaN or infinite pivot, from non-finite entries
+            let positive = diag > T::Real::zero() && diag.is_finite();
+            if !positive {
+                return Err(NotPositiveDefiniteError);
+            } else {
+                let lii = diag.sqrt();
+                chol_l[(i, i)] = T::from_real(lii);
+                for j in i + 1..n {
+                    let mut off_diag = self[(j, i)];
                     for k in 0..i {
-                        diag -= chol_l[(i, k)] * chol_l[(i, k)];
-                    }
-                    if diag < 0.0 {
-                        return Err(NotPositiveDefiniteError);
-                    } else {
-                        chol_l[(i, i)] = diag.sqrt();
-                        for j in i + 1..n {
-                            let mut off_diag = self[(i, j)];
-                            for k in 0..i {
-                                off_diag -= chol_l[(i, k)] * chol_l[(j, k)];
-                            }
-                            chol_l[(j, i)] = (1 as $type) / chol_l[(i, i)] * off_diag;
-                        }
+                        off_diag -= chol_l[(j, k)] * chol_l[(i, k)].conj();
                     }
+                    chol_l[(j, i)] = T::from_real(T::Real::one() / lii) * off_diag;
                 }
-
-                Ok(chol_l)
             }
         }
-    };
+
+        Ok(CholeskyFactorization {
+            lt: chol_l.adjoint(),
+            l: chol_l,
+        })
+    }
 }
 
-impl_cholesky!(f32);
-impl_cholesky!(f64);
+impl<T> Cholesky<T> for MatrixView<'_, T>
+where
+    T: Copy,
+    Matrix<T>: Cholesky<T>,
+{
+    fn chol(&self) -> Result<CholeskyFactorization<T>, CholDecompositionError> {
+        self.to_matrix().chol()
+    }
+}
 
 #[cfg(test)]
 mod test {
@@ -61,6 +169,7 @@ mod test {
         assert!(chol.is_ok());
 
         let chol = chol.unwrap();
+        let chol = chol.l();
         assert_relative_eq!(chol[(0, 0)], 2.0);
         assert_relative_eq!(chol[(1, 0)], 6.0);
         assert_relative_eq!(chol[(2, 0)], -8.0);
@@ -71,7 +180,7 @@ mod test {
         assert_relative_eq!(chol[(1, 2)], 0.0);
         assert_relative_eq!(chol[(2, 2)], 3.0);
 
-        let m_rec = gemm(&chol, &chol.transpose());
+        let m_rec = gemm(chol, &chol.transpose());
         assert_eq!(m_rec.n_rows, m.n_rows);
         assert_eq!(m_rec.n_cols, m.n_cols);
         for i in 0..m_rec.n_rows {
@@ -98,4 +207,83 @@ mod test {
         assert!(chol.is_err());
         assert_eq!(chol.unwrap_err(), NotPositiveDefiniteError);
     }
+
+    #[test]
+    fn test_cholesky_semidefinite() {
+        let m: Matrix<f64> = matrix![1.0, 1.0; 1.0, 1.0];
+
+        let chol = m.chol();
+        assert!(chol.is_err());
+        assert_eq!(chol.unwrap_err(), NotPositiveDefiniteError);
+    }
+
+    #[test]
+    fn test_cholesky_non_finite() {
+        // An infinite pivot, and one that is NaN from inf - inf in the second step
+        let m: Matrix<f64> = matrix![f64::INFINITY, 1.0; 1.0, 1.0];
+        assert_eq!(m.chol().unwrap_err(), NotPositiveDefiniteError);
+        let m: Matrix<f64> = matrix![1.0, f64::INFINITY; f64::INFINITY, f64::INFINITY];
+        assert_eq!(m.chol().unwrap_err(), NotPositiveDefiniteError);
+    }
+
+    #[test]
+    fn test_cholesky_solve_det_inverse() {
+        let m: Matrix<f64> = matrix![4.0, 12.0, -16.0; 12.0, 37.0, -43.0; -16.0, -43.0, 98.0];
+        let chol = m.chol().unwrap();
+
+        let x = chol.solve(&Vector::from_vec(&[-4.0, -9.5, 33.0]));
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(x[1], 0.0, epsilon = 1e-10);
+        assert_relative_eq!(x[2], 0.5, epsilon = 1e-10);
+
+        assert_relative_eq!(chol.det(), 36.0, epsilon = 1e-10);
+        assert_relative_eq!(chol.log_det(), 36.0f64.ln(), epsilon = 1e-12);
+
+        let inv = chol.inverse();
+        let eye = gemm(&m, &inv);
+        for i in 0..3 {
+            for j in 0..3 {
+                let delta = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(eye[(i, j)], delta, epsilon = 1e-10);
+            }
+        }
+
+        let rhs: Matrix<f64> = matrix![4.0, -4.0; 12.0, -9.5; -16.0, 33.0];
+        let x = chol.solve_matrix(&rhs);
+        assert_relative_eq!(x[(0, 0)], 1.0, epsilon = 1e-10);
+        assert_relative_eq!(x[(1, 0)], 0.0, epsilon = 1e-10);
+        assert_relative_eq!(x[(2, 1)], 0.5, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn test_cholesky_complex() {
+        use crate::core::complex::Complex;
+
+        let z = |re, im| Complex::new(re, im);
+        let m: Matrix<Complex<f64>> = matrix![z(4.0, 0.0), z(2.0, -2.0); z(2.0, 2.0), z(11.0, 0.0)];
+
+        let chol = m.chol().unwrap();
+        let l = chol.l();
+        for (lij, expected) in
+            l.as_slice()
+                .iter()
+                .zip([z(2.0, 0.0), z(0.0, 0.0), z(1.0, 1.0), z(3.0, 0.0)])
+        {
+            assert_relative_eq!(lij.re, expected.re, epsilon = 1e-12);
+            assert_relative_eq!(lij.im, expected.im, epsilon = 1e-12);
+        }
+
+        let x = chol.solve(&Vector::from([z(6.0, 2.0), z(2.0, 13.0)]));
+        assert_relative_eq!(x[0].re, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[0].im, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1].re, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1].im, 1.0, epsilon = 1e-12);
+
+        assert_relative_eq!(chol.det().re, 36.0, epsilon = 1e-10);
+        assert_relative_eq!(chol.log_det(), 36.0f64.ln(), epsilon = 1e-12);
+
+        // Symmetric but not Hermitian
+        let s = matrix![z(4.0, 0.0), z(0.0, 1.0); z(0.0, 1.0), z(4.0, 0.0)];
+        assert_eq!(s.chol().unwrap_err(), NotSymmetricError);
+    }
 }
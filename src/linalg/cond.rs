@@ -0,0 +1,229 @@
+use crate::core::gemm::dotc;
+use crate::core::scalar::{ComplexField, One, Zero};
+use crate::core::vector::Vector;
+use crate::linalg::chol::CholeskyFactorization;
+use crate::linalg::lu::LuFactorization;
+use crate::linalg::solve::{bsub, fsub};
+
+/// Iterations of the estimator after which it stops even if it has not converged. Higham
+/// reports that it almost always converges within four or five.
+const MAX_ITERATIONS: usize = 5;
+
+/// Estimates `||A^-1||_1` for an `n x n` matrix `A` given functions solving `A x = b` and
+/// `A^H x = b`, with Higham's refinement (Algorithm 4.1 of "FORTRAN codes for estimating the
+/// one-norm of a real or complex matrix", 1988) of Hager's method.
+///
+/// Each iteration maximizes `||A^-1 x||_1` over the unit ball by a step of gradient ascent from
+/// a vertex, so the estimate is a lower bound, and in practice rarely lower than a third of the
+/// exact value. It needs a handful of solves, `O(n^2)` operations each.
+fn inverse_norm1_estimate<T, F, G>(n: usize, solve: F, solve_adjoint: G) -> T::Real
+where
+    T: ComplexField,
+    F: Fn(&Vector<T>) -> Vector<T>,
+    G: Fn(&Vector<T>) -> Vector<T>,
+{
+    if n == 0 {
+        return T::Real::zero();
+    }
+
+    let sign = |y: &T| {
+        let abs = y.modulus();
+        if abs.is_zero() {
+            T::one()
+        } else {
+            *y / T::from_real(abs)
+        }
+    };
+
+    let mut n_real = T::Real::zero();
+    for _ in 0..n {
+        n_real += T::Real::one();
+    }
+    let mut x = Vector::from_fn(n, |_| T::from_real(T::Real::one() / n_real));
+    let mut estimate = T::Real::zero();
+    for k in 0..MAX_ITERATIONS {
+        let y = solve(&x);
+        estimate = y.norm1();
+
+        let z = solve_adjoint(&Vector::from_fn(n, |i| sign(&y[i])));
+        let j = z.iamax().unwrap();
+        // Stop at a local maximum, where no vertex improves on the current x
+        if k > 0 && z[j].modulus() <= dotc(&z, &x).re() {
+            break;
+        }
+        x = Vector::from_fn(n, |i| if i == j { T::one() } else { T::zero() });
+    }
+
+    // The iteration can be fooled by matrices built against it. Alternating entries of
+    // increasing size, which it is unlikely to be orthogonal to, give a second lower bound
+    let mut step = T::Real::zero();
+    for _ in 1..n {
+        step += T::Real::one();
+    }
+    let step = if n > 1 {
+        T::Real::one() / step
+    } else {
+        T::Real::zero()
+    };
+    let mut offset = T::Real::one();
+    let x = Vector::from_fn(n, |i| {
+        let xi = T::from_real(offset);
+        offset += step;
+        if i % 2 == 0 {
+            xi
+        } else {
+            -xi
+        }
+    });
+    let three = T::Real::one() + T::Real::one() + T::Real::one();
+    let alternative = (T::Real::one() + T::Real::one()) * solve(&x).norm1() / (three * n_real);
+
+    if alternative > estimate {
+        alternative
+    } else {
+        estimate
+    }
+}
+
+impl<T> LuFactorization<T>
+where
+    T: ComplexField,
+{
+    /// Estimate of `||A^-1||_1` computed from the factorization, without forming the inverse.
+    /// It is a lower bound, and seldom off by more than a factor of three.
+    pub fn inverse_norm1_estimate(&self) -> T::Real {
+        // A = P^T L U, so A^H x = b is U^H w = b, L^H v = w and x = P^T v
+        let (lh, uh, pt) = (self.l.adjoint(), self.u.adjoint(), self.perm.inverse());
+
+        inverse_norm1_estimate(
+            self.u.n_rows,
+            |b| self.solve(b),
+            |b| pt.apply_vec(&bsub(&lh, &fsub(&uh, b))),
+        )
+    }
+
+    /// Estimate of the 1-norm condition number `||A||_1 * ||A^-1||_1` of the factorized matrix,
+    /// given `norm1 = ||A||_1`. Solving a system with this matrix can lose up to about
+    /// `log10(cond)` decimal digits of accuracy.
+    pub fn cond1_estimate(&self, norm1: T::Real) -> T::Real {
+        norm1 * self.inverse_norm1_estimate()
+    }
+}
+
+impl<T> CholeskyFactorization<T>
+where
+    T: ComplexField,
+{
+    /// Estimate of `||A^-1||_1` computed from the factorization, without forming the inverse.
+    /// It is a lower bound, and seldom off by more than a factor of three.
+    pub fn inverse_norm1_estimate(&self) -> T::Real {
+        // A is Hermitian, and so is its inverse
+        inverse_norm1_estimate(self.l.n_rows, |b| self.solve(b), |b| self.solve(b))
+    }
+
+    /// Estimate of the 1-norm condition number `||A||_1 * ||A^-1||_1` of the factorized matrix,
+    /// given `norm1 = ||A||_1`. Solving a system with this matrix can lose up to about
+    /// `log10(cond)` decimal digits of accuracy.
+    pub fn cond1_estimate(&self, norm1: T::Real) -> T::Real {
+        norm1 * self.inverse_norm1_estimate()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+
+    use crate::core::complex::Complex;
+    use crate::core::matrix::Matrix;
+    use crate::linalg::chol::Cholesky;
+    use crate::linalg::lu::LU;
+    use crate::matrix;
+
+    /// Checks the estimate against the exact norm of the inverse.
+    fn check_lu(a: &Matrix<f64>) -> f64 {
+        let lu = a.lu().unwrap();
+        let exact = lu.inverse().norm1();
+        let estimate = lu.inverse_norm1_estimate();
+        assert!(estimate <= exact * (1.0 + 1e-10), "{estimate} > {exact}");
+        assert!(estimate >= exact / 3.0, "{estimate} << {exact}");
+
+        estimate / exact
+    }
+
+    #[test]
+    fn test_lu_estimate() {
+        let a: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+        let lu = a.lu().unwrap();
+        assert_relative_eq!(lu.inverse_norm1_estimate(), 3.5, epsilon = 1e-12);
+        assert_relative_eq!(lu.cond1_estimate(a.norm1()), 21.0, epsilon = 1e-12);
+
+        // Pseudo-random matrices of several sizes, where the estimate is most often exact
+        let mut exact = 0;
+        for n in 1..12 {
+            for seed in 0..5 {
+                let mut state: u64 = seed + 1;
+                let a = Matrix::from_fn(n, n, |_, _| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    (state >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+                });
+                if (check_lu(&a) - 1.0).abs() < 1e-10 {
+                    exact += 1;
+                }
+            }
+        }
+        assert!(exact >= 40, "{exact} of 55 estimates exact");
+
+        assert_eq!(matrix![0.5].lu().unwrap().inverse_norm1_estimate(), 2.0);
+    }
+
+    #[test]
+    fn test_ill_conditioned() {
+        let hilbert = |n| Matrix::from_fn(n, n, |i, j| 1.0 / (i + j + 1) as f64);
+
+        let h = hilbert(4);
+        let cond = h.lu().unwrap().cond1_estimate(h.norm1());
+        assert_relative_eq!(cond, 28375.0, max_relative = 1e-8);
+        assert_relative_eq!(
+            h.chol().unwrap().cond1_estimate(h.norm1()),
+            cond,
+            max_relative = 1e-8
+        );
+
+        // Solving with these loses about 10 and 16 digits, so the latter is numerically singular
+        let h = hilbert(8);
+        assert!(h.lu().unwrap().cond1_estimate(h.norm1()) > 1e10);
+        let h = hilbert(12);
+        assert!(h.lu().unwrap().cond1_estimate(h.norm1()) * f64::EPSILON > 1.0);
+    }
+
+    #[test]
+    fn test_cholesky_estimate() {
+        let a: Matrix<f64> = matrix![4.0, 12.0, -16.0; 12.0, 37.0, -43.0; -16.0, -43.0, 98.0];
+        let chol = a.chol().unwrap();
+        let exact = chol.inverse().norm1();
+        assert_relative_eq!(chol.inverse_norm1_estimate(), exact, max_relative = 1e-10);
+        assert_relative_eq!(
+            chol.cond1_estimate(a.norm1()),
+            a.norm1() * exact,
+            max_relative = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_complex_estimate() {
+        let z = |re, im| Complex::new(re, im);
+        let a: Matrix<Complex<f64>> = matrix![z(1.0, 0.0), z(0.0, 1.0), z(2.0, -1.0); z(2.0, 1.0), z(1.0, 0.0), z(0.0, 0.0); z(0.0, -3.0), z(1.0, 1.0), z(4.0, 0.0)];
+        let lu = a.lu().unwrap();
+        let exact = lu.inverse().norm1();
+        let estimate = lu.inverse_norm1_estimate();
+        assert!(estimate <= exact * (1.0 + 1e-10) && estimate >= exact / 3.0);
+
+        let h: Matrix<Complex<f64>> = matrix![z(4.0, 0.0), z(2.0, -2.0); z(2.0, 2.0), z(11.0, 0.0)];
+        let chol = h.chol().unwrap();
+        let exact = chol.inverse().norm1();
+        let estimate = chol.inverse_norm1_estimate();
+        assert!(estimate <= exact * (1.0 + 1e-10) && estimate >= exact / 3.0);
+    }
+}
@@ -0,0 +1,1300 @@
+use std::cmp::Ordering;
+
+use crate::core::complex::Complex;
+use crate::core::error::EigenDecompositionError;
+use crate::core::error::EigenDecompositionError::{
+    NoConvergenceError, NotSquareError, NotSymmetricError,
+};
+use crate::core::matrix::*;
+use crate::core::vector::Vector;
+use crate::core::view::MatrixView;
+
+/// Eigendecomposition `A = V * diag(values) * V^T` of a real symmetric matrix, with eigenvalues
+/// sorted in decreasing order and orthonormal eigenvectors stored as the columns of `V`.
+#[derive(Debug)]
+pub struct SymmetricEigenDecomposition<T> {
+    pub(crate) values: Vector<T>,
+    pub(crate) vectors: Matrix<T>,
+}
+
+pub trait SymmetricEigen<T> {
+    /// Computes eigenvalues and eigenvectors of a symmetric matrix.
+    fn symmetric_eigen(&self) -> Result<SymmetricEigenDecomposition<T>, EigenDecompositionError>;
+
+    /// Computes only the eigenvalues of a symmetric matrix, in decreasing order.
+    fn symmetric_eigenvalues(&self) -> Result<Vector<T>, EigenDecompositionError>;
+}
+
+impl<T> SymmetricEigenDecomposition<T> {
+    /// Eigenvalues, largest first.
+    pub fn values(&self) -> &Vector<T> {
+        &self.values
+    }
+
+    /// Eigenvectors, one per column, in the same order as the eigenvalues.
+    pub fn vectors(&self) -> &Matrix<T> {
+        &self.vectors
+    }
+}
+
+#[macro_export]
+macro_rules! impl_symmetric_eigen {
+    ($type:ty) => {
+        impl SymmetricEigen<$type> for Matrix<$type> {
+            fn symmetric_eigen(
+                &self,
+            ) -> Result<SymmetricEigenDecomposition<$type>, EigenDecompositionError> {
+                let (values, vectors) =
+                    SymmetricEigenDecomposition::<$type>::decompose(self, true)?;
+
+                Ok(SymmetricEigenDecomposition { values, vectors })
+            }
+
+            fn symmetric_eigenvalues(&self) -> Result<Vector<$type>, EigenDecompositionError> {
+                let (values, _) = SymmetricEigenDecomposition::<$type>::decompose(self, false)?;
+
+                Ok(values)
+            }
+        }
+
+        impl SymmetricEigenDecomposition<$type> {
+            fn decompose(
+                a: &Matrix<$type>,
+                yesvecs: bool,
+            ) -> Result<(Vector<$type>, Matrix<$type>), EigenDecompositionError> {
+                if !a.is_symmetric() {
+                    return Err(NotSymmetricError);
+                }
+
+                let n = a.n_rows;
+                let mut z = a.clone();
+                let mut d = Vector::new(n);
+                let mut e = Vector::new(n);
+                if n == 0 {
+                    return Ok((d, z));
+                }
+
+                Self::tred2(&mut z, &mut d, &mut e, yesvecs);
+                Self::tqli(&mut z, &mut d, &mut e, yesvecs)?;
+
+                // Sort eigenvalues (and eigenvectors) in decreasing order
+                let mut order: Vec<usize> = (0..n).collect();
+                order.sort_by(|&a, &b| d[b].partial_cmp(&d[a]).unwrap_or(Ordering::Equal));
+
+                let values = Vector {
+                    n,
+                    data: order.iter().map(|&j| d[j]).collect(),
+                };
+                let mut vectors = Matrix::<$type>::zeros(n, if yesvecs { n } else { 0 });
+                if yesvecs {
+                    for (k, &j) in order.iter().enumerate() {
+                        for i in 0..n {
+                            vectors[(i, k)] = z[(i, j)];
+                        }
+                    }
+                }
+
+                Ok((values, vectors))
+            }
+
+            /// Householder reduction of the symmetric matrix `z` to tridiagonal form, with
+            /// diagonal `d` and subdiagonal `e[1..n]`. If `yesvecs` is set, `z` is replaced by
+            /// the orthogonal matrix effecting the transformation.
+            fn tred2(
+                z: &mut Matrix<$type>,
+                d: &mut Vector<$type>,
+                e: &mut Vector<$type>,
+                yesvecs: bool,
+            ) {
+                let n = z.n_rows;
+
+                for i in (1..n).rev() {
+                    let l = i - 1;
+                    let mut h: $type = 0.0;
+                    if l > 0 {
+                        let mut scale: $type = 0.0;
+                        for k in 0..i {
+                            scale += z[(i, k)].abs();
+                        }
+                        if scale == 0.0 {
+                            // Skip the transformation
+                            e[i] = z[(i, l)];
+                        } else {
+                            for k in 0..i {
+                                z[(i, k)] /= scale;
+                                h += z[(i, k)] * z[(i, k)];
+                            }
+                            let mut f = z[(i, l)];
+                            let mut g = if f >= 0.0 { -h.sqrt() } else { h.sqrt() };
+                            e[i] = scale * g;
+                            h -= f * g;
+                            z[(i, l)] = f - g;
+                            f = 0.0;
+                            for j in 0..i {
+                                if yesvecs {
+                                    z[(j, i)] = z[(i, j)] / h;
+                                }
+                                // Form an element of A * u in g
+                                g = 0.0;
+                                for k in 0..j + 1 {
+                                    g += z[(j, k)] * z[(i, k)];
+                                }
+                                for k in j + 1..i {
+                                    g += z[(k, j)] * z[(i, k)];
+                                }
+                                // Form an element of p in e
+                                e[j] = g / h;
+                                f += e[j] * z[(i, j)];
+                            }
+                            let hh = f / (h + h);
+                            for j in 0..i {
+                                let f = z[(i, j)];
+                                let g = e[j] - hh * f;
+                                e[j] = g;
+                                for k in 0..j + 1 {
+                                    let zik = z[(i, k)];
+                                    z[(j, k)] -= f * e[k] + g * zik;
+                                }
+                            }
+                        }
+                    } else {
+                        e[i] = z[(i, l)];
+                    }
+                    d[i] = h;
+                }
+
+                if yesvecs {
+                    d[0] = 0.0;
+                }
+                e[0] = 0.0;
+
+                for i in 0..n {
+                    if yesvecs {
+                        // Accumulate the transformations
+                        if d[i] != 0.0 {
+                            for j in 0..i {
+                                let mut g = 0.0;
+                                for k in 0..i {
+                                    g += z[(i, k)] * z[(k, j)];
+                                }
+                                for k in 0..i {
+                                    let zki = z[(k, i)];
+                                    z[(k, j)] -= g * zki;
+                                }
+                            }
+                        }
+                        d[i] = z[(i, i)];
+                        z[(i, i)] = 1.0;
+                        for j in 0..i {
+                            z[(j, i)] = 0.0;
+                            z[(i, j)] = 0.0;
+                        }
+                    } else {
+                        d[i] = z[(i, i)];
+                    }
+                }
+            }
+
+            /// QL algorithm with implicit shifts on the tridiagonal matrix produced by `tred2`.
+            /// On exit `d` holds the eigenvalues and, if `yesvecs` is set, the columns of `z` the
+            /// corresponding eigenvectors.
+            fn tqli(
+                z: &mut Matrix<$type>,
+                d: &mut Vector<$type>,
+                e: &mut Vector<$type>,
+                yesvecs: bool,
+            ) -> Result<(), EigenDecompositionError> {
+                const MAX_ITERATIONS: usize = 30;
+
+                let n = z.n_rows;
+                let eps = <$type>::EPSILON;
+
+                // Renumber the subdiagonal for convenience
+                for i in 1..n {
+                    e[i - 1] = e[i];
+                }
+                e[n - 1] = 0.0;
+
+                for l in 0..n {
+                    let mut iter = 0;
+                    loop {
+                        // Look for a single small subdiagonal element to split the matrix
+                        let mut m = l;
+                        while m < n - 1 {
+                            let dd = d[m].abs() + d[m + 1].abs();
+                            if e[m].abs() <= eps * dd {
+                                break;
+                            }
+                            m += 1;
+                        }
+                        if m == l {
+                            break;
+                        }
+
+                        if iter == MAX_ITERATIONS {
+                            return Err(NoConvergenceError);
+                        }
+                        iter += 1;
+
+                        // Form the shift
+                        let mut g = (d[l + 1] - d[l]) / (2.0 * e[l]);
+                        let mut r = g.hypot(1.0);
+                        g = d[m] - d[l] + e[l] / (g + if g >= 0.0 { r.abs() } else { -r.abs() });
+
+                        let mut s = 1.0;
+                        let mut c = 1.0;
+                        let mut p = 0.0;
+                        let mut underflow = false;
+                        for i in (l..m).rev() {
+                            let mut f = s * e[i];
+                            let b = c * e[i];
+                            r = f.hypot(g);
+                            e[i + 1] = r;
+                            if r == 0.0 {
+                                // Recover from underflow
+                                d[i + 1] -= p;
+                                e[m] = 0.0;
+                                underflow = true;
+                                break;
+                            }
+                            s = f / r;
+                            c = g / r;
+                            g = d[i + 1] - p;
+                            r = (d[i] - g) * s + 2.0 * c * b;
+                            p = s * r;
+                            d[i + 1] = g + p;
+                            g = c * r - b;
+
+                            if yesvecs {
+                                for k in 0..n {
+                                    f = z[(k, i + 1)];
+                                    z[(k, i + 1)] = s * z[(k, i)] + c * f;
+                                    z[(k, i)] = c * z[(k, i)] - s * f;
+                                }
+                            }
+                        }
+                        if underflow {
+                            continue;
+                        }
+
+                        d[l] -= p;
+                        e[l] = g;
+                        e[m] = 0.0;
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    };
+}
+
+impl_symmetric_eigen!(f32);
+impl_symmetric_eigen!(f64);
+
+impl<T> SymmetricEigen<T> for MatrixView<'_, T>
+where
+    T: Copy,
+    Matrix<T>: SymmetricEigen<T>,
+{
+    fn symmetric_eigen(&self) -> Result<SymmetricEigenDecomposition<T>, EigenDecompositionError> {
+        self.to_matrix().symmetric_eigen()
+    }
+
+    fn symmetric_eigenvalues(&self) -> Result<Vector<T>, EigenDecompositionError> {
+        self.to_matrix().symmetric_eigenvalues()
+    }
+}
+
+/// Options for the eigendecomposition of a general real matrix.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct EigenOptions {
+    /// Balance the matrix with a diagonal similarity transformation before reducing it, which
+    /// usually improves the accuracy of the eigenvalues. Ignored when `schur` is set, since the
+    /// Schur vectors are only orthogonal for the unbalanced matrix.
+    pub balance: bool,
+    /// Compute the eigenvectors.
+    pub vectors: bool,
+    /// Keep the real Schur form `A = Z * T * Z^T`.
+    pub schur: bool,
+}
+
+impl Default for EigenOptions {
+    fn default() -> Self {
+        Self {
+            balance: true,
+            vectors: true,
+            schur: false,
+        }
+    }
+}
+
+/// Eigendecomposition of a general real matrix.
+///
+/// Eigenvalues are sorted by decreasing real part, with complex conjugate pairs ordered by
+/// decreasing imaginary part. Each eigenvector has unit Euclidean norm.
+#[derive(Debug)]
+pub struct EigenDecomposition<T> {
+    pub(crate) values: Vector<Complex<T>>,
+    pub(crate) vectors: Option<Matrix<Complex<T>>>,
+    pub(crate) schur: Option<(Matrix<T>, Matrix<T>)>,
+}
+
+pub trait Eigen<T> {
+    /// Computes eigenvalues, and optionally eigenvectors and the real Schur form, of a general
+    /// square matrix.
+    fn eigen(
+        &self,
+        options: EigenOptions,
+    ) -> Result<EigenDecomposition<T>, EigenDecompositionError>;
+
+    /// Computes only the eigenvalues of a general square matrix.
+    fn eigenvalues(&self) -> Result<Vector<Complex<T>>, EigenDecompositionError>;
+}
+
+impl<T> EigenDecomposition<T> {
+    pub fn values(&self) -> &Vector<Complex<T>> {
+        &self.values
+    }
+
+    /// Eigenvectors, one per column, in the same order as the eigenvalues.
+    pub fn vectors(&self) -> Option<&Matrix<Complex<T>>> {
+        self.vectors.as_ref()
+    }
+
+    /// Quasi upper triangular factor `T` of the real Schur form, with a 2 x 2 block on the
+    /// diagonal for every complex conjugate pair of eigenvalues.
+    pub fn schur_form(&self) -> Option<&Matrix<T>> {
+        self.schur.as_ref().map(|(t, _)| t)
+    }
+
+    /// Orthogonal factor `Z` of the real Schur form.
+    pub fn schur_vectors(&self) -> Option<&Matrix<T>> {
+        self.schur.as_ref().map(|(_, z)| z)
+    }
+}
+
+#[macro_export]
+macro_rules! impl_eigen {
+    ($type:ty) => {
+        impl Eigen<$type> for Matrix<$type> {
+            fn eigen(
+                &self,
+                options: EigenOptions,
+            ) -> Result<EigenDecomposition<$type>, EigenDecompositionError> {
+                if self.n_rows != self.n_cols {
+                    return Err(NotSquareError);
+                }
+
+                let n = self.n_rows;
+                let mut h = self.clone();
+                let scale = if options.balance && !options.schur {
+                    EigenDecomposition::<$type>::balance(&mut h)
+                } else {
+                    vec![1.0; n]
+                };
+
+                let mut v = EigenDecomposition::<$type>::orthes(&mut h);
+                let mut d = vec![0.0; n];
+                let mut e = vec![0.0; n];
+                let norm = EigenDecomposition::<$type>::hqr2(&mut h, &mut v, &mut d, &mut e)?;
+
+                let schur = if options.schur {
+                    // Clear the entries below the diagonal that are not part of a 2 x 2 block
+                    let mut t = Matrix::<$type>::zeros(n, n);
+                    for i in 0..n {
+                        for j in i.saturating_sub(1)..n {
+                            let pair = j + 1 == i && e[j] > 0.0;
+                            if j >= i || pair {
+                                t[(i, j)] = h[(i, j)];
+                            }
+                        }
+                    }
+                    let z = v.clone();
+
+                    Some((t, z))
+                } else {
+                    None
+                };
+
+                let mut order: Vec<usize> = (0..n).collect();
+                order.sort_by(|&a, &b| {
+                    d[b].partial_cmp(&d[a])
+                        .unwrap_or(Ordering::Equal)
+                        .then(e[b].partial_cmp(&e[a]).unwrap_or(Ordering::Equal))
+                });
+                let values = Vector {
+                    n,
+                    data: order.iter().map(|&j| Complex::new(d[j], e[j])).collect(),
+                };
+
+                let vectors = if options.vectors {
+                    if norm != 0.0 {
+                        EigenDecomposition::<$type>::backsubstitute(&mut h, &mut v, &d, &e, norm);
+                    }
+                    for i in 0..n {
+                        for j in 0..n {
+                            v[(i, j)] *= scale[i];
+                        }
+                    }
+
+                    // Complex pairs are stored as real and imaginary parts in adjacent columns
+                    let mut vectors = Matrix::new(n, n);
+                    for (k, &j) in order.iter().enumerate() {
+                        let (re, im, sign) = if e[j] > 0.0 {
+                            (j, Some(j + 1), 1.0)
+                        } else if e[j] < 0.0 {
+                            (j - 1, Some(j), -1.0)
+                        } else {
+                            (j, None, 0.0)
+                        };
+
+                        let mut norm: $type = 0.0;
+                        for i in 0..n {
+                            let x = Complex::new(v[(i, re)], im.map_or(0.0, |c| sign * v[(i, c)]));
+                            norm = norm.hypot(x.abs());
+                            vectors[(i, k)] = x;
+                        }
+                        if norm != 0.0 {
+                            for i in 0..n {
+                                vectors[(i, k)].re /= norm;
+                                vectors[(i, k)].im /= norm;
+                            }
+                        }
+                    }
+
+                    Some(vectors)
+                } else {
+                    None
+                };
+
+                Ok(EigenDecomposition {
+                    values,
+                    vectors,
+                    schur,
+                })
+            }
+
+            fn eigenvalues(&self) -> Result<Vector<Complex<$type>>, EigenDecompositionError> {
+                let options = EigenOptions {
+                    balance: true,
+                    vectors: false,
+                    schur: false,
+                };
+
+                Ok(self.eigen(options)?.values)
+            }
+        }
+
+        impl EigenDecomposition<$type> {
+            /// Replaces `a` by the balanced matrix `D^-1 * a * D`, where `D` is a diagonal matrix
+            /// of powers of two chosen so that corresponding rows and columns have similar norms.
+            /// Returns the diagonal of `D`.
+            fn balance(a: &mut Matrix<$type>) -> Vec<$type> {
+                const RADIX: $type = 2.0;
+
+                let n = a.n_rows;
+                let mut scale = vec![1.0; n];
+                let sqrdx = RADIX * RADIX;
+
+                let mut done = false;
+                while !done {
+                    done = true;
+                    for i in 0..n {
+                        let mut r: $type = 0.0;
+                        let mut c: $type = 0.0;
+                        for j in 0..n {
+                            if j != i {
+                                c += a[(j, i)].abs();
+                                r += a[(i, j)].abs();
+                            }
+                        }
+                        if c != 0.0 && r != 0.0 {
+                            let s = c + r;
+                            let mut f = 1.0;
+                            let g = r / RADIX;
+                            while c < g {
+                                f *= RADIX;
+                                c *= sqrdx;
+                            }
+                            let g = r * RADIX;
+                            while c > g {
+                                f /= RADIX;
+                                c /= sqrdx;
+                            }
+                            if (c + r) / f < 0.95 * s {
+                                done = false;
+                                scale[i] *= f;
+                                for j in 0..n {
+                                    a[(i, j)] /= f;
+                                }
+                                for j in 0..n {
+                                    a[(j, i)] *= f;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                scale
+            }
+
+            /// Reduces `h` to upper Hessenberg form by orthogonal similarity transformations and
+            /// returns the accumulated orthogonal matrix.
+            fn orthes(h: &mut Matrix<$type>) -> Matrix<$type> {
+                let n = h.n_rows;
+                let mut ort: Vec<$type> = vec![0.0; n];
+
+                for m in 1..n.saturating_sub(1) {
+                    let mut scale = 0.0;
+                    for i in m..n {
+                        scale += h[(i, m - 1)].abs();
+                    }
+                    if scale == 0.0 {
+                        continue;
+                    }
+
+                    // Householder transformation
+                    let mut hh = 0.0;
+                    for i in (m..n).rev() {
+                        ort[i] = h[(i, m - 1)] / scale;
+                        hh += ort[i] * ort[i];
+                    }
+                    let g = if ort[m] > 0.0 { -hh.sqrt() } else { hh.sqrt() };
+                    hh -= ort[m] * g;
+                    ort[m] -= g;
+
+                    // H = (I - u * u^T / h) * H * (I - u * u^T / h)
+                    for j in m..n {
+                        let mut f = 0.0;
+                        for i in (m..n).rev() {
+                            f += ort[i] * h[(i, j)];
+                        }
+                        f /= hh;
+                        for i in m..n {
+                            h[(i, j)] -= f * ort[i];
+                        }
+                    }
+                    for i in 0..n {
+                        let mut f = 0.0;
+                        for j in (m..n).rev() {
+                            f += ort[j] * h[(i, j)];
+                        }
+                        f /= hh;
+                        for j in m..n {
+                            h[(i, j)] -= f * ort[j];
+                        }
+                    }
+                    ort[m] *= scale;
+                    h[(m, m - 1)] = scale * g;
+                }
+
+                // Accumulate the transformations
+                let mut v = Matrix::<$type>::eye(n);
+                for m in (1..n.saturating_sub(1)).rev() {
+                    if h[(m, m - 1)] != 0.0 {
+                        for i in m + 1..n {
+                            ort[i] = h[(i, m - 1)];
+                        }
+                        for j in m..n {
+                            let mut g = 0.0;
+                            for i in m..n {
+                                g += ort[i] * v[(i, j)];
+                            }
+                            // Double division avoids possible underflow
+                            g = (g / ort[m]) / h[(m, m - 1)];
+                            for i in m..n {
+                                v[(i, j)] += g * ort[i];
+                            }
+                        }
+                    }
+                }
+
+                v
+            }
+
+            /// Francis double-shift QR iteration on the Hessenberg matrix `h`, accumulating the
+            /// transformations into `v`. On exit `h` is quasi upper triangular and the real and
+            /// imaginary parts of the eigenvalues are in `d` and `e`, with the eigenvalue of a
+            /// complex pair with positive imaginary part first. Returns the norm of `h`.
+            fn hqr2(
+                h: &mut Matrix<$type>,
+                v: &mut Matrix<$type>,
+                d: &mut [$type],
+                e: &mut [$type],
+            ) -> Result<$type, EigenDecompositionError> {
+                let nn = h.n_rows;
+                let eps = <$type>::EPSILON;
+                let mut exshift: $type = 0.0;
+                let (mut p, mut q, mut r, mut s, mut z): ($type, $type, $type, $type, $type);
+                let (mut w, mut x, mut y): ($type, $type, $type);
+
+                let mut norm: $type = 0.0;
+                for i in 0..nn {
+                    for j in i.saturating_sub(1)..nn {
+                        norm += h[(i, j)].abs();
+                    }
+                }
+
+                let max_iterations = 30 * nn.max(1);
+                let mut total_iter = 0;
+                let mut iter = 0;
+                let mut hi = nn;
+                while hi > 0 {
+                    let n = hi - 1;
+
+                    // Look for a single small subdiagonal element
+                    let mut l = n;
+                    while l > 0 {
+                        s = h[(l - 1, l - 1)].abs() + h[(l, l)].abs();
+                        if s == 0.0 {
+                            s = norm;
+                        }
+                        // Not strict, so that a zero block deflates even when s is zero too
+                        if h[(l, l - 1)].abs() <= eps * s {
+                            break;
+                        }
+                        l -= 1;
+                    }
+
+                    if l == n {
+                        // One root found
+                        h[(n, n)] += exshift;
+                        d[n] = h[(n, n)];
+                        e[n] = 0.0;
+                        hi -= 1;
+                        iter = 0;
+                    } else if l == n - 1 {
+                        // Two roots found
+                        w = h[(n, n - 1)] * h[(n - 1, n)];
+                        p = (h[(n - 1, n - 1)] - h[(n, n)]) / 2.0;
+                        q = p * p + w;
+                        z = q.abs().sqrt();
+                        h[(n, n)] += exshift;
+                        h[(n - 1, n - 1)] += exshift;
+                        x = h[(n, n)];
+
+                        if q >= 0.0 {
+                            // Real pair, rotate the block to upper triangular form
+                            z = if p >= 0.0 { p + z } else { p - z };
+                            d[n - 1] = x + z;
+                            d[n] = d[n - 1];
+                            if z != 0.0 {
+                                d[n] = x - w / z;
+                            }
+                            e[n - 1] = 0.0;
+                            e[n] = 0.0;
+                            x = h[(n, n - 1)];
+                            s = x.abs() + z.abs();
+                            p = x / s;
+                            q = z / s;
+                            r = (p * p + q * q).sqrt();
+                            p /= r;
+                            q /= r;
+
+                            for j in n - 1..nn {
+                                z = h[(n - 1, j)];
+                                h[(n - 1, j)] = q * z + p * h[(n, j)];
+                                h[(n, j)] = q * h[(n, j)] - p * z;
+                            }
+                            for i in 0..=n {
+                                z = h[(i, n - 1)];
+                                h[(i, n - 1)] = q * z + p * h[(i, n)];
+                                h[(i, n)] = q * h[(i, n)] - p * z;
+                            }
+                            for i in 0..nn {
+                                z = v[(i, n - 1)];
+                                v[(i, n - 1)] = q * z + p * v[(i, n)];
+                                v[(i, n)] = q * v[(i, n)] - p * z;
+                            }
+                        } else {
+                            // Complex pair
+                            d[n - 1] = x + p;
+                            d[n] = x + p;
+                            e[n - 1] = z;
+                            e[n] = -z;
+                        }
+                        hi -= 2;
+                        iter = 0;
+                    } else {
+                        if total_iter == max_iterations {
+                            return Err(NoConvergenceError);
+                        }
+
+                        // Form the shift
+                        x = h[(n, n)];
+                        y = h[(n - 1, n - 1)];
+                        w = h[(n, n - 1)] * h[(n - 1, n)];
+
+                        // Wilkinson's original ad hoc shift
+                        if iter == 10 {
+                            exshift += x;
+                            for i in 0..=n {
+                                h[(i, i)] -= x;
+                            }
+                            s = h[(n, n - 1)].abs() + h[(n - 1, n - 2)].abs();
+                            x = 0.75 * s;
+                            y = x;
+                            w = -0.4375 * s * s;
+                        }
+
+                        // MATLAB's ad hoc shift
+                        if iter == 30 {
+                            s = (y - x) / 2.0;
+                            s = s * s + w;
+                            if s > 0.0 {
+                                s = s.sqrt();
+                                if y < x {
+                                    s = -s;
+                                }
+                                s = x - w / ((y - x) / 2.0 + s);
+                                for i in 0..=n {
+                                    h[(i, i)] -= s;
+                                }
+                                exshift += s;
+                                x = 0.964;
+                                y = x;
+                                w = x;
+                            }
+                        }
+
+                        iter += 1;
+                        total_iter += 1;
+
+                        // Look for two consecutive small subdiagonal elements
+                        let mut m = n - 2;
+                        loop {
+                            z = h[(m, m)];
+                            r = x - z;
+                            s = y - z;
+                            p = (r * s - w) / h[(m + 1, m)] + h[(m, m + 1)];
+                            q = h[(m + 1, m + 1)] - z - r - s;
+                            r = h[(m + 2, m + 1)];
+                            s = p.abs() + q.abs() + r.abs();
+                            p /= s;
+                            q /= s;
+                            r /= s;
+                            if m == l {
+                                break;
+                            }
+                            if h[(m, m - 1)].abs() * (q.abs() + r.abs())
+                                < eps
+                                    * (p.abs()
+                                        * (h[(m - 1, m - 1)].abs()
+                                            + z.abs()
+                                            + h[(m + 1, m + 1)].abs()))
+                            {
+                                break;
+                            }
+                            m -= 1;
+                        }
+
+                        for i in m + 2..=n {
+                            h[(i, i - 2)] = 0.0;
+                            if i > m + 2 {
+                                h[(i, i - 3)] = 0.0;
+                            }
+                        }
+
+                        // Double QR step involving rows l..=n and columns m..=n
+                        for k in m..n {
+                            let notlast = k != n - 1;
+                            if k != m {
+                                p = h[(k, k - 1)];
+                                q = h[(k + 1, k - 1)];
+                                r = if notlast { h[(k + 2, k - 1)] } else { 0.0 };
+                                x = p.abs() + q.abs() + r.abs();
+                                if x == 0.0 {
+                                    continue;
+                                }
+                                p /= x;
+                                q /= x;
+                                r /= x;
+                            }
+
+                            s = (p * p + q * q + r * r).sqrt();
+                            if p < 0.0 {
+                                s = -s;
+                            }
+                            if s != 0.0 {
+                                if k != m {
+                                    h[(k, k - 1)] = -s * x;
+                                } else if l != m {
+                                    h[(k, k - 1)] = -h[(k, k - 1)];
+                                }
+                                p += s;
+                                x = p / s;
+                                y = q / s;
+                                z = r / s;
+                                q /= p;
+                                r /= p;
+
+                                // Row modification
+                                for j in k..nn {
+                                    p = h[(k, j)] + q * h[(k + 1, j)];
+                                    if notlast {
+                                        p += r * h[(k + 2, j)];
+                                        h[(k + 2, j)] -= p * z;
+                                    }
+                                    h[(k, j)] -= p * x;
+                                    h[(k + 1, j)] -= p * y;
+                                }
+
+                                // Column modification
+                                for i in 0..=n.min(k + 3) {
+                                    p = x * h[(i, k)] + y * h[(i, k + 1)];
+                                    if notlast {
+                                        p += z * h[(i, k + 2)];
+                                        h[(i, k + 2)] -= p * r;
+                                    }
+                                    h[(i, k)] -= p;
+                                    h[(i, k + 1)] -= p * q;
+                                }
+
+                                // Accumulate transformations
+                                for i in 0..nn {
+                                    p = x * v[(i, k)] + y * v[(i, k + 1)];
+                                    if notlast {
+                                        p += z * v[(i, k + 2)];
+                                        v[(i, k + 2)] -= p * r;
+                                    }
+                                    v[(i, k)] -= p;
+                                    v[(i, k + 1)] -= p * q;
+                                }
+                            }
+                        }
+                    }
+                }
+
+                Ok(norm)
+            }
+
+            /// Computes the eigenvectors of the quasi triangular matrix left in `h` by `hqr2`
+            /// and transforms them back with `v`. On exit each real eigenvector is a column of
+            /// `v`, and each complex pair is stored as real and imaginary parts in two adjacent
+            /// columns.
+            fn backsubstitute(
+                h: &mut Matrix<$type>,
+                v: &mut Matrix<$type>,
+                d: &[$type],
+                e: &[$type],
+                norm: $type,
+            ) {
+                let nn = h.n_rows;
+                let eps = <$type>::EPSILON;
+                let (mut r, mut s, mut z): ($type, $type, $type) = (0.0, 0.0, 0.0);
+                let (mut t, mut w, mut x, mut y): ($type, $type, $type, $type);
+
+                let cdiv = |xr: $type, xi: $type, yr: $type, yi: $type| -> ($type, $type) {
+                    if yr.abs() > yi.abs() {
+                        let r = yi / yr;
+                        let d = yr + r * yi;
+                        ((xr + r * xi) / d, (xi - r * xr) / d)
+                    } else {
+                        let r = yr / yi;
+                        let d = yi + r * yr;
+                        ((r * xr + xi) / d, (r * xi - xr) / d)
+                    }
+                };
+
+                for n in (0..nn).rev() {
+                    let p = d[n];
+                    let q = e[n];
+
+                    if q == 0.0 {
+                        // Real vector
+                        let mut l = n;
+                        h[(n, n)] = 1.0;
+                        for i in (0..n).rev() {
+                            w = h[(i, i)] - p;
+                            r = 0.0;
+                            for j in l..=n {
+                                r += h[(i, j)] * h[(j, n)];
+                            }
+                            if e[i] < 0.0 {
+                                z = w;
+                                s = r;
+                            } else {
+                                l = i;
+                                if e[i] == 0.0 {
+                                    h[(i, n)] = if w != 0.0 { -r / w } else { -r / (eps * norm) };
+                                } else {
+                                    // Solve real equations
+                                    x = h[(i, i + 1)];
+                                    y = h[(i + 1, i)];
+                                    let q = (d[i] - p) * (d[i] - p) + e[i] * e[i];
+                                    t = (x * s - z * r) / q;
+                                    h[(i, n)] = t;
+                                    h[(i + 1, n)] = if x.abs() > z.abs() {
+                                        (-r - w * t) / x
+                                    } else {
+                                        (-s - y * t) / z
+                                    };
+                                }
+
+                                // Overflow control
+                                t = h[(i, n)].abs();
+                                if (eps * t) * t > 1.0 {
+                                    for j in i..=n {
+                                        h[(j, n)] /= t;
+                                    }
+                                }
+                            }
+                        }
+                    } else if q < 0.0 {
+                        // Complex vector, the last component is chosen imaginary so the
+                        // system is triangular
+                        let mut l = n - 1;
+                        if h[(n, n - 1)].abs() > h[(n - 1, n)].abs() {
+                            h[(n - 1, n - 1)] = q / h[(n, n - 1)];
+                            h[(n - 1, n)] = -(h[(n, n)] - p) / h[(n, n - 1)];
+                        } else {
+                            let (cr, ci) = cdiv(0.0, -h[(n - 1, n)], h[(n - 1, n - 1)] - p, q);
+                            h[(n - 1, n - 1)] = cr;
+                            h[(n - 1, n)] = ci;
+                        }
+                        h[(n, n - 1)] = 0.0;
+                        h[(n, n)] = 1.0;
+
+                        let (mut ra, mut sa): ($type, $type);
+                        for i in (0..n - 1).rev() {
+                            ra = 0.0;
+                            sa = 0.0;
+                            for j in l..=n {
+                                ra += h[(i, j)] * h[(j, n - 1)];
+                                sa += h[(i, j)] * h[(j, n)];
+                            }
+                            w = h[(i, i)] - p;
+
+                            if e[i] < 0.0 {
+                                z = w;
+                                r = ra;
+                                s = sa;
+                            } else {
+                                l = i;
+                                if e[i] == 0.0 {
+                                    let (cr, ci) = cdiv(-ra, -sa, w, q);
+                                    h[(i, n - 1)] = cr;
+                                    h[(i, n)] = ci;
+                                } else {
+                                    // Solve complex equations
+                                    x = h[(i, i + 1)];
+                                    y = h[(i + 1, i)];
+                                    let mut vr = (d[i] - p) * (d[i] - p) + e[i] * e[i] - q * q;
+                                    let vi = (d[i] - p) * 2.0 * q;
+                                    if vr == 0.0 && vi == 0.0 {
+                                        vr = eps
+                                            * norm
+                                            * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                                    }
+                                    let (cr, ci) = cdiv(
+                                        x * r - z * ra + q * sa,
+                                        x * s - z * sa - q * ra,
+                                        vr,
+                                        vi,
+                                    );
+                                    h[(i, n - 1)] = cr;
+                                    h[(i, n)] = ci;
+                                    if x.abs() > z.abs() + q.abs() {
+                                        h[(i + 1, n - 1)] =
+                                            (-ra - w * h[(i, n - 1)] + q * h[(i, n)]) / x;
+                                        h[(i + 1, n)] =
+                                            (-sa - w * h[(i, n)] - q * h[(i, n - 1)]) / x;
+                                    } else {
+                                        let (cr, ci) =
+                                            cdiv(-r - y * h[(i, n - 1)], -s - y * h[(i, n)], z, q);
+                                        h[(i + 1, n - 1)] = cr;
+                                        h[(i + 1, n)] = ci;
+                                    }
+                                }
+
+                                // Overflow control
+                                t = h[(i, n - 1)].abs().max(h[(i, n)].abs());
+                                if (eps * t) * t > 1.0 {
+                                    for j in i..=n {
+                                        h[(j, n - 1)] /= t;
+                                        h[(j, n)] /= t;
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+
+                // Back transformation to the eigenvectors of the original matrix
+                for j in (0..nn).rev() {
+                    for i in 0..nn {
+                        z = 0.0;
+                        for k in 0..=j {
+                            z += v[(i, k)] * h[(k, j)];
+                        }
+                        v[(i, j)] = z;
+                    }
+                }
+            }
+        }
+    };
+}
+
+impl_eigen!(f32);
+impl_eigen!(f64);
+
+impl<T> Eigen<T> for MatrixView<'_, T>
+where
+    T: Copy,
+    Matrix<T>: Eigen<T>,
+{
+    fn eigen(
+        &self,
+        options: EigenOptions,
+    ) -> Result<EigenDecomposition<T>, EigenDecompositionError> {
+        self.to_matrix().eigen(options)
+    }
+
+    fn eigenvalues(&self) -> Result<Vector<Complex<T>>, EigenDecompositionError> {
+        self.to_matrix().eigenvalues()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::gemm::gemm;
+
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_symmetric_eigen() {
+        let m: Matrix<f64> = matrix![2.0, -1.0, 0.0; -1.0, 2.0, -1.0; 0.0, -1.0, 2.0];
+
+        let eigen = m.symmetric_eigen().unwrap();
+        let values = eigen.values();
+        assert_relative_eq!(values[0], 2.0 + 2f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(values[1], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(values[2], 2.0 - 2f64.sqrt(), epsilon = 1e-12);
+
+        let v = eigen.vectors();
+        let av = gemm(&m, v);
+        let vtv = gemm(&v.transpose(), v);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(av[(i, j)], v[(i, j)] * values[j], epsilon = 1e-12);
+                assert_relative_eq!(vtv[(i, j)], if i == j { 1.0 } else { 0.0 }, epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_symmetric_eigen_larger() {
+        let m: Matrix<f64> = Matrix::from_gen(6, 6, |i, j| 1.0 / ((i + j + 1) as f64));
+
+        let eigen = m.symmetric_eigen().unwrap();
+        let v = eigen.vectors();
+        let av = gemm(&m, v);
+        for i in 0..6 {
+            for j in 0..6 {
+                assert_relative_eq!(av[(i, j)], v[(i, j)] * eigen.values()[j], epsilon = 1e-12);
+            }
+        }
+        for j in 1..6 {
+            assert!(eigen.values()[j - 1] >= eigen.values()[j]);
+        }
+    }
+
+    #[test]
+    fn test_symmetric_eigenvalues() {
+        let m: Matrix<f32> = matrix![4.0, 1.0; 1.0, 4.0];
+
+        let values = m.symmetric_eigenvalues().unwrap();
+        assert_eq!(values.n, 2);
+        assert_relative_eq!(values[0], 5.0, epsilon = 1e-5);
+        assert_relative_eq!(values[1], 3.0, epsilon = 1e-5);
+
+        let m: Matrix<f64> = Matrix::from_gen(5, 5, |i, j| (i * j) as f64 + (i + j) as f64);
+        let values = m.symmetric_eigenvalues().unwrap();
+        let eigen = m.symmetric_eigen().unwrap();
+        for i in 0..5 {
+            assert_relative_eq!(values[i], eigen.values()[i], epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_symmetric_eigen_not_symmetric() {
+        let m: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+
+        let eigen = m.symmetric_eigen();
+        assert!(eigen.is_err());
+        assert_eq!(eigen.unwrap_err(), NotSymmetricError);
+
+        let m: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+        assert_eq!(m.symmetric_eigenvalues().unwrap_err(), NotSymmetricError);
+    }
+
+    fn check_eigenpairs(a: &Matrix<f64>, eigen: &EigenDecomposition<f64>) {
+        let n = a.n_rows;
+        let v = eigen.vectors().unwrap();
+        let mut ac = Matrix::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                ac[(i, j)] = Complex::from(a[(i, j)]);
+            }
+        }
+
+        let av = gemm(&ac, v);
+        for k in 0..n {
+            let lambda = eigen.values()[k];
+            let mut norm: f64 = 0.0;
+            for i in 0..n {
+                let d = av[(i, k)] - lambda * v[(i, k)];
+                assert_relative_eq!(d.abs(), 0.0, epsilon = 1e-10);
+                norm += v[(i, k)].norm_sqr();
+            }
+            assert_relative_eq!(norm, 1.0, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_eigen_rotation() {
+        let m: Matrix<f64> = matrix![0.0, -1.0; 1.0, 0.0];
+
+        let eigen = m.eigen(EigenOptions::default()).unwrap();
+        let values = eigen.values();
+        assert_relative_eq!(values[0].re, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(values[0].im, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(values[1].re, 0.0, epsilon = 1e-12);
+        assert_relative_eq!(values[1].im, -1.0, epsilon = 1e-12);
+        assert!(eigen.schur_form().is_none());
+        check_eigenpairs(&m, &eigen);
+
+        let v = eigen.vectors().unwrap();
+        assert_eq!(v[(0, 0)], v[(0, 1)].conj());
+        assert_eq!(v[(1, 0)], v[(1, 1)].conj());
+    }
+
+    #[test]
+    fn test_eigen_companion() {
+        // Companion matrix of (x - 1)(x - 2)(x - 3)
+        let m: Matrix<f64> = matrix![6.0, -11.0, 6.0; 1.0, 0.0, 0.0; 0.0, 1.0, 0.0];
+
+        let eigen = m.eigen(EigenOptions::default()).unwrap();
+        for (i, expected) in [3.0, 2.0, 1.0].iter().enumerate() {
+            assert_relative_eq!(eigen.values()[i].re, *expected, epsilon = 1e-10);
+            assert_relative_eq!(eigen.values()[i].im, 0.0);
+        }
+        check_eigenpairs(&m, &eigen);
+
+        let m: Matrix<f32> = matrix![6.0, -11.0, 6.0; 1.0, 0.0, 0.0; 0.0, 1.0, 0.0];
+        let values = m.eigenvalues().unwrap();
+        assert_relative_eq!(values[0].re, 3.0, epsilon = 1e-4);
+        assert_relative_eq!(values[2].re, 1.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn test_eigen_mixed() {
+        let m: Matrix<f64> = matrix![
+            4.0, -2.0, 1.0, 0.5;
+            3.0, 1.0, -1.0, 2.0;
+            0.0, 2.0, 5.0, -3.0;
+            1.0, 0.0, 4.0, 2.0
+        ];
+
+        let eigen = m.eigen(EigenOptions::default()).unwrap();
+        check_eigenpairs(&m, &eigen);
+
+        let values = eigen.values();
+        let trace = values
+            .data
+            .iter()
+            .fold(Complex::new(0.0, 0.0), |s, &x| s + x);
+        assert_relative_eq!(trace.re, 12.0, epsilon = 1e-10);
+        assert_relative_eq!(trace.im, 0.0, epsilon = 1e-10);
+        for i in 1..4 {
+            assert!(values[i - 1].re >= values[i].re);
+        }
+    }
+
+    #[test]
+    fn test_eigen_schur() {
+        let m: Matrix<f64> = matrix![
+            1.0, 2.0, 0.0, -1.0;
+            -3.0, 1.0, 2.0, 0.0;
+            0.0, 1.0, 2.0, 1.0;
+            2.0, 0.0, -1.0, 3.0
+        ];
+
+        let options = EigenOptions {
+            schur: true,
+            ..Default::default()
+        };
+        let eigen = m.eigen(options).unwrap();
+        let t = eigen.schur_form().unwrap();
+        let z = eigen.schur_vectors().unwrap();
+
+        // T is quasi upper triangular with no two consecutive nonzero subdiagonal entries
+        for i in 0..4usize {
+            for j in 0..i.saturating_sub(1) {
+                assert_eq!(t[(i, j)], 0.0);
+            }
+        }
+        for i in 2..4 {
+            assert!(t[(i, i - 1)] == 0.0 || t[(i - 1, i - 2)] == 0.0);
+        }
+
+        let ztz = gemm(&z.transpose(), z);
+        let a = gemm(&gemm(z, t), &z.transpose());
+        for i in 0..4 {
+            for j in 0..4 {
+                let delta = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(ztz[(i, j)], delta, epsilon = 1e-12);
+                assert_relative_eq!(a[(i, j)], m[(i, j)], epsilon = 1e-12);
+            }
+        }
+        check_eigenpairs(&m, &eigen);
+    }
+
+    #[test]
+    fn test_eigen_defective() {
+        let m: Matrix<f64> = matrix![2.0, 1.0; 0.0, 2.0];
+
+        let eigen = m.eigen(EigenOptions::default()).unwrap();
+        assert_relative_eq!(eigen.values()[0].re, 2.0);
+        assert_relative_eq!(eigen.values()[1].re, 2.0);
+        check_eigenpairs(&m, &eigen);
+    }
+
+    #[test]
+    fn test_eigen_zero() {
+        let zero = Complex::new(0.0, 0.0);
+        for n in 1..6 {
+            let m = Matrix::<f64>::zeros(n, n);
+            let eigen = m.eigen(EigenOptions::default()).unwrap();
+            assert!(eigen.values().data.iter().all(|&x| x == zero));
+            check_eigenpairs(&m, &eigen);
+        }
+
+        // Nilpotent, and with a zero block next to a nonzero one
+        let m: Matrix<f64> = matrix![0.0, 1.0, 2.0; 0.0, 0.0, 3.0; 0.0, 0.0, 0.0];
+        let values = m.eigenvalues().unwrap();
+        assert!(values.data.iter().all(|&x| x == zero));
+
+        let m: Matrix<f64> = matrix![
+            0.0, 0.0, 0.0, 1.0;
+            0.0, 0.0, 0.0, 0.0;
+            0.0, 0.0, 0.0, 0.0;
+            0.0, 0.0, 0.0, 5.0
+        ];
+        let eigen = m.eigen(EigenOptions::default()).unwrap();
+        assert_eq!(eigen.values()[0], Complex::new(5.0, 0.0));
+        assert!(eigen.values().data[1..].iter().all(|&x| x == zero));
+        check_eigenpairs(&m, &eigen);
+    }
+
+    #[test]
+    fn test_eigen_not_square() {
+        let m: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+
+        let eigen = m.eigen(EigenOptions::default());
+        assert!(eigen.is_err());
+        assert_eq!(eigen.unwrap_err(), NotSquareError);
+    }
+}
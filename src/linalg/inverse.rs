@@ -0,0 +1,197 @@
+use crate::core::error::LUDecompositionError::SingularMatrixError;
+use crate::core::error::{CholDecompositionError, LUDecompositionError};
+use crate::core::matrix::Matrix;
+use crate::core::scalar::{Field, RealField};
+use crate::linalg::chol::Cholesky;
+use crate::linalg::lu::LU;
+
+pub trait Determinant<T> {
+    /// Determinant computed through LU decomposition with partial pivoting. A singular matrix
+    /// has determinant zero.
+    fn det(&self) -> Result<T, LUDecompositionError>;
+
+    /// Sign and natural logarithm of the absolute value of the determinant, `(sign, log|det|)`,
+    /// for matrices whose determinant overflows or underflows. Fails on singular matrices.
+    fn log_abs_det(&self) -> Result<(T, T), LUDecompositionError>
+    where
+        T: RealField;
+
+    /// Determinant of a symmetric positive definite matrix, computed through Cholesky
+    /// decomposition.
+    fn det_spd(&self) -> Result<T, CholDecompositionError>
+    where
+        T: RealField,
+        Self: Cholesky<T>;
+
+    /// Natural logarithm of the determinant of a symmetric positive definite matrix.
+    fn log_det_spd(&self) -> Result<T, CholDecompositionError>
+    where
+        T: RealField,
+        Self: Cholesky<T>;
+}
+
+pub trait Inverse<T> {
+    /// Inverse computed through LU decomposition with partial pivoting.
+    fn inverse(&self) -> Result<Matrix<T>, LUDecompositionError>;
+
+    /// Inverse of a symmetric positive definite matrix, computed through Cholesky
+    /// decomposition.
+    fn inverse_spd(&self) -> Result<Matrix<T>, CholDecompositionError>
+    where
+        T: RealField,
+        Self: Cholesky<T>;
+}
+
+impl<T, M> Determinant<T> for M
+where
+    T: Field,
+    M: LU<T> + ?Sized,
+{
+    fn det(&self) -> Result<T, LUDecompositionError> {
+        match self.lu() {
+            Ok(lu) => Ok(lu.det()),
+            Err(SingularMatrixError) => Ok(T::zero()),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn log_abs_det(&self) -> Result<(T, T), LUDecompositionError>
+    where
+        T: RealField,
+    {
+        let lu = self.lu()?;
+
+        Ok((lu.sign(), lu.log_det()))
+    }
+
+    fn det_spd(&self) -> Result<T, CholDecompositionError>
+    where
+        T: RealField,
+        Self: Cholesky<T>,
+    {
+        Ok(self.chol()?.det())
+    }
+
+    fn log_det_spd(&self) -> Result<T, CholDecompositionError>
+    where
+        T: RealField,
+        Self: Cholesky<T>,
+    {
+        Ok(self.chol()?.log_det())
+    }
+}
+
+impl<T, M> Inverse<T> for M
+where
+    T: Field,
+    M: LU<T> + ?Sized,
+{
+    fn inverse(&self) -> Result<Matrix<T>, LUDecompositionError> {
+        Ok(self.lu()?.inverse())
+    }
+
+    fn inverse_spd(&self) -> Result<Matrix<T>, CholDecompositionError>
+    where
+        T: RealField,
+        Self: Cholesky<T>,
+    {
+        Ok(self.chol()?.inverse())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+
+    use crate::core::error::CholDecompositionError::NotPositiveDefiniteError;
+    use crate::core::error::LUDecompositionError::NotSquareError;
+    use crate::core::gemm::gemm;
+    use crate::matrix;
+
+    use super::*;
+
+    #[test]
+    fn test_det() {
+        let m: Matrix<f64> = matrix![2.0, 1.0, 1.0; 4.0, -6.0, 0.0; -2.0, 7.0, 2.0];
+        assert_relative_eq!(m.det().unwrap(), -16.0, epsilon = 1e-12);
+
+        let (sign, log_abs_det) = m.log_abs_det().unwrap();
+        assert_relative_eq!(sign, -1.0);
+        assert_relative_eq!(log_abs_det, 16.0f64.ln(), epsilon = 1e-12);
+
+        let m: Matrix<f32> = matrix![4.0, 3.0; 6.0, 3.0];
+        assert_relative_eq!(m.det().unwrap(), -6.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_det_singular() {
+        let m: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0];
+
+        assert_eq!(m.det().unwrap(), 0.0);
+        assert_eq!(m.log_abs_det().unwrap_err(), SingularMatrixError);
+        assert_eq!(m.inverse().unwrap_err(), SingularMatrixError);
+    }
+
+    #[test]
+    fn test_log_abs_det_large() {
+        // det = 10^400 overflows f64, but its logarithm does not
+        let mut m = Matrix::<f64>::zeros(200, 200);
+        for i in 0..200 {
+            m[(i, i)] = if i % 2 == 0 { 100.0 } else { -100.0 };
+        }
+
+        assert!(m.det().unwrap().is_infinite());
+        let (sign, log_abs_det) = m.log_abs_det().unwrap();
+        assert_relative_eq!(sign, 1.0);
+        assert_relative_eq!(log_abs_det, 400.0 * 10.0f64.ln(), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_inverse() {
+        let m: Matrix<f64> = matrix![0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 3.0, 0.0, 1.0];
+
+        let inv = m.inverse().unwrap();
+        let eye = gemm(&m, &inv);
+        for i in 0..3 {
+            for j in 0..3 {
+                let delta = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(eye[(i, j)], delta, epsilon = 1e-12);
+            }
+        }
+
+        let m: Matrix<f32> = matrix![4.0, 7.0; 2.0, 6.0];
+        let inv = m.inverse().unwrap();
+        assert_relative_eq!(inv[(0, 0)], 0.6, epsilon = 1e-6);
+        assert_relative_eq!(inv[(0, 1)], -0.7, epsilon = 1e-6);
+        assert_relative_eq!(inv[(1, 0)], -0.2, epsilon = 1e-6);
+        assert_relative_eq!(inv[(1, 1)], 0.4, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_spd() {
+        let m: Matrix<f64> = matrix![4.0, 12.0, -16.0; 12.0, 37.0, -43.0; -16.0, -43.0, 98.0];
+
+        assert_relative_eq!(m.det_spd().unwrap(), 36.0, epsilon = 1e-10);
+        assert_relative_eq!(m.log_det_spd().unwrap(), 36.0f64.ln(), epsilon = 1e-12);
+
+        let inv = m.inverse_spd().unwrap();
+        let inv_lu = m.inverse().unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(inv[(i, j)], inv_lu[(i, j)], epsilon = 1e-9);
+            }
+        }
+
+        let m: Matrix<f64> = matrix![1.0, 2.0; 2.0, 1.0];
+        assert_eq!(m.det_spd().unwrap_err(), NotPositiveDefiniteError);
+        assert_eq!(m.inverse_spd().unwrap_err(), NotPositiveDefiniteError);
+    }
+
+    #[test]
+    fn test_not_square() {
+        let m: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+
+        assert_eq!(m.det().unwrap_err(), NotSquareError);
+        assert_eq!(m.inverse().unwrap_err(), NotSquareError);
+    }
+}
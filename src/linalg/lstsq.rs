@@ -0,0 +1,279 @@
+use crate::core::error::LeastSquaresError;
+use crate::core::error::LeastSquaresError::RankDeficientError;
+use crate::core::gemm::gemm;
+use crate::core::matrix::*;
+use crate::core::vector::Vector;
+use crate::core::view::MatrixView;
+use crate::linalg::chol::Cholesky;
+use crate::linalg::qr::QR;
+
+/// Factorization used to solve a least-squares problem.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum LstsqMethod {
+    /// Householder QR with column pivoting. Handles rank-deficient matrices by returning the
+    /// basic solution, which has zeros in the components of the dropped columns.
+    #[default]
+    QR,
+    /// Cholesky factorization of the normal equations `A^T A x = A^T b`. Cheaper for very tall
+    /// matrices, but squares the condition number and requires full column rank.
+    NormalEquations,
+}
+
+#[derive(Debug)]
+pub struct LstsqSolution<T> {
+    pub x: Vector<T>,
+    /// Euclidean norm of the residual `b - A x`.
+    pub residual: T,
+    /// Effective rank of `A`.
+    pub rank: usize,
+}
+
+#[derive(Debug)]
+pub struct LstsqMatrixSolution<T> {
+    /// One solution per column of the right-hand side.
+    pub x: Matrix<T>,
+    /// Euclidean norm of the residual of each column.
+    pub residuals: Vector<T>,
+    /// Effective rank of `A`.
+    pub rank: usize,
+}
+
+pub trait LeastSquares<T> {
+    /// Minimizes `||A x - rhs||_2`.
+    fn lstsq(
+        &self,
+        rhs: &Vector<T>,
+        method: LstsqMethod,
+    ) -> Result<LstsqSolution<T>, LeastSquaresError>;
+
+    /// Minimizes `||A X - rhs||_F`, i.e. solves the least-squares problem for every column of
+    /// `rhs` at the cost of a single factorization.
+    fn lstsq_matrix(
+        &self,
+        rhs: &Matrix<T>,
+        method: LstsqMethod,
+    ) -> Result<LstsqMatrixSolution<T>, LeastSquaresError>;
+}
+
+/// Solves `min ||A x - b||_2` using column-pivoted QR.
+pub fn lstsq<T, M>(lhs: &M, rhs: &Vector<T>) -> Result<LstsqSolution<T>, LeastSquaresError>
+where
+    M: LeastSquares<T> + ?Sized,
+{
+    lhs.lstsq(rhs, LstsqMethod::default())
+}
+
+#[macro_export]
+macro_rules! impl_lstsq {
+    ($type:ty) => {
+        impl LeastSquares<$type> for Matrix<$type> {
+            fn lstsq(
+                &self,
+                rhs: &Vector<$type>,
+                method: LstsqMethod,
+            ) -> Result<LstsqSolution<$type>, LeastSquaresError> {
+                let rhs = Matrix {
+                    n_rows: rhs.n,
+                    n_cols: 1,
+                    data: rhs.data.clone(),
+                    layout: Layout::RowMajor,
+                };
+                let solution = self.lstsq_matrix(&rhs, method)?;
+
+                Ok(LstsqSolution {
+                    x: Vector {
+                        n: solution.x.n_rows,
+                        data: solution.x.data,
+                    },
+                    residual: solution.residuals[0],
+                    rank: solution.rank,
+                })
+            }
+
+            fn lstsq_matrix(
+                &self,
+                rhs: &Matrix<$type>,
+                method: LstsqMethod,
+            ) -> Result<LstsqMatrixSolution<$type>, LeastSquaresError> {
+                if self.n_rows != rhs.n_rows {
+                    panic!(
+                        "Least-squares problem with incompatible dimensions: {:?} and {:?}",
+                        (self.n_rows, self.n_cols),
+                        (rhs.n_rows, rhs.n_cols)
+                    );
+                }
+
+                let (m, n, p) = (self.n_rows, self.n_cols, rhs.n_cols);
+                let mut x = Matrix::<$type>::zeros(n, p);
+                let rank = match method {
+                    LstsqMethod::QR => {
+                        let (qr, perm) = self.qr_pivoted();
+                        let r = qr.reflectors();
+                        let k = m.min(n);
+
+                        // |R_ii| is non-increasing, so the rank is the length of the prefix of
+                        // diagonal entries that are not negligible relative to the first one
+                        let r_max = if k > 0 { r[(0, 0)].abs() } else { 0.0 };
+                        let tol = r_max * (m.max(n) as $type) * <$type>::EPSILON;
+                        let rank = (0..k).take_while(|&i| r[(i, i)].abs() > tol).count();
+
+                        let qtb = qr.apply_qt_matrix(rhs);
+                        for c in 0..p {
+                            for i in (0..rank).rev() {
+                                let mut s = qtb[(i, c)];
+                                for j in i + 1..rank {
+                                    s -= r[(i, j)] * x[(perm[j], c)];
+                                }
+                                x[(perm[i], c)] = s / r[(i, i)];
+                            }
+                        }
+
+                        rank
+                    }
+                    LstsqMethod::NormalEquations => {
+                        // Build A^T A explicitly symmetric so the Cholesky check passes
+                        let mut gram = Matrix::<$type>::zeros(n, n);
+                        for i in 0..n {
+                            for j in i..n {
+                                let mut s = 0.0;
+                                for k in 0..m {
+                                    s += self[(k, i)] * self[(k, j)];
+                                }
+                                gram[(i, j)] = s;
+                                gram[(j, i)] = s;
+                            }
+                        }
+
+                        let chol = gram.chol().map_err(|_| RankDeficientError)?;
+                        let l = chol.l();
+                        let mut g_max: $type = 0.0;
+                        for i in 0..n {
+                            g_max = g_max.max(gram[(i, i)]);
+                        }
+                        let tol = g_max * (n as $type) * <$type>::EPSILON;
+                        for i in 0..n {
+                            if l[(i, i)] * l[(i, i)] <= tol {
+                                return Err(RankDeficientError);
+                            }
+                        }
+
+                        x = chol.solve_matrix(&gemm(&self.transpose(), rhs));
+                        n
+                    }
+                };
+
+                let ax = gemm(self, &x);
+                let mut residuals = Vector::new(p);
+                for c in 0..p {
+                    let mut s = 0.0;
+                    for i in 0..m {
+                        let d = rhs[(i, c)] - ax[(i, c)];
+                        s += d * d;
+                    }
+                    residuals[c] = s.sqrt();
+                }
+
+                Ok(LstsqMatrixSolution { x, residuals, rank })
+            }
+        }
+    };
+}
+
+impl_lstsq!(f32);
+impl_lstsq!(f64);
+
+impl<T> LeastSquares<T> for MatrixView<'_, T>
+where
+    T: Copy,
+    Matrix<T>: LeastSquares<T>,
+{
+    fn lstsq(
+        &self,
+        rhs: &Vector<T>,
+        method: LstsqMethod,
+    ) -> Result<LstsqSolution<T>, LeastSquaresError> {
+        self.to_matrix().lstsq(rhs, method)
+    }
+
+    fn lstsq_matrix(
+        &self,
+        rhs: &Matrix<T>,
+        method: LstsqMethod,
+    ) -> Result<LstsqMatrixSolution<T>, LeastSquaresError> {
+        self.to_matrix().lstsq_matrix(rhs, method)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn test_lstsq_line_fit() {
+        // y = 1 + 2 t sampled at t = 0..4, with the last point perturbed
+        let a: Matrix<f64> = matrix![1.0, 0.0; 1.0, 1.0; 1.0, 2.0; 1.0, 3.0; 1.0, 4.0];
+        let b = Vector::from_vec(&[1.0, 3.0, 5.0, 7.0, 10.0]);
+
+        for method in [LstsqMethod::QR, LstsqMethod::NormalEquations] {
+            let solution = a.lstsq(&b, method).unwrap();
+            assert_eq!(solution.rank, 2);
+            assert_relative_eq!(solution.x[0], 0.8, epsilon = 1e-10);
+            assert_relative_eq!(solution.x[1], 2.2, epsilon = 1e-10);
+            assert_relative_eq!(solution.residual, 0.4f64.sqrt(), epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_lstsq_exact() {
+        let a: Matrix<f32> = matrix![2.0, 1.0; 1.0, 3.0; 0.0, 1.0];
+        let b = Vector::from_vec(&[4.0, 7.0, 2.0]);
+
+        let solution = lstsq(&a, &b).unwrap();
+        assert_relative_eq!(solution.x[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(solution.x[1], 2.0, epsilon = 1e-5);
+        assert_relative_eq!(solution.residual, 0.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_lstsq_multiple_rhs() {
+        let a: Matrix<f64> = matrix![1.0, 0.0; 1.0, 1.0; 1.0, 2.0; 1.0, 3.0; 1.0, 4.0];
+        let b: Matrix<f64> = matrix![1.0, 0.0; 3.0, 1.0; 5.0, 2.0; 7.0, 3.0; 10.0, 4.0];
+
+        for method in [LstsqMethod::QR, LstsqMethod::NormalEquations] {
+            let solution = a.lstsq_matrix(&b, method).unwrap();
+            assert_eq!((solution.x.n_rows, solution.x.n_cols), (2, 2));
+            assert_relative_eq!(solution.x[(0, 0)], 0.8, epsilon = 1e-10);
+            assert_relative_eq!(solution.x[(1, 0)], 2.2, epsilon = 1e-10);
+            assert_relative_eq!(solution.x[(0, 1)], 0.0, epsilon = 1e-10);
+            assert_relative_eq!(solution.x[(1, 1)], 1.0, epsilon = 1e-10);
+            assert_relative_eq!(solution.residuals[0], 0.4f64.sqrt(), epsilon = 1e-10);
+            assert_relative_eq!(solution.residuals[1], 0.0, epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_lstsq_rank_deficient() {
+        let a: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.0];
+        let b = Vector::from_vec(&[2.0, 4.0, 6.0]);
+
+        let solution = a.lstsq(&b, LstsqMethod::QR).unwrap();
+        assert_eq!(solution.rank, 1);
+        assert_relative_eq!(solution.x[0], 0.0);
+        assert_relative_eq!(solution.x[1], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(solution.residual, 0.0, epsilon = 1e-12);
+
+        let solution = a.lstsq(&b, LstsqMethod::NormalEquations);
+        assert!(solution.is_err());
+        assert_eq!(solution.unwrap_err(), RankDeficientError);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_lstsq_dims() {
+        let a: Matrix<f64> = matrix![1.0, 0.0; 1.0, 1.0; 1.0, 2.0];
+        let b = Vector::from_vec(&[1.0, 2.0]);
+
+        let _ = lstsq(&a, &b);
+    }
+}
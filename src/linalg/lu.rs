@@ -1,87 +1,336 @@
-use crate::core::error::LUDecompositionError;
-use crate::core::error::LUDecompositionError::NotSquareError;
+use crate::core::error::LUDecompositionError::{NotSquareError, SingularMatrixError};
+use crate::core::error::ShapeError::DimensionMismatchError;
+use crate::core::error::{LUDecompositionError, ShapeError};
 use crate::core::matrix::*;
+use crate::core::permutation::Permutation;
+use crate::core::scalar::{ComplexField, Field, RealField, Zero};
+use crate::core::vector::Vector;
+use crate::core::view::MatrixView;
+use crate::linalg::solve::{bsub, fsub};
 
-pub(crate) trait LU<T> {
-    fn lu(&self) -> Result<(Matrix<T>, Matrix<T>), LUDecompositionError>;
+/// LU decomposition with partial pivoting, `P * A = L * U`, where `L` is unit lower triangular
+/// and `U` is upper triangular.
+#[derive(Debug)]
+pub struct LuFactorization<T> {
+    pub(crate) perm: Permutation,
+    pub(crate) l: Matrix<T>,
+    pub(crate) u: Matrix<T>,
 }
 
-#[macro_export]
-macro_rules! impl_lu {
-    ($type:ty) => {
-        impl LU<$type> for Matrix<$type> {
-            fn lu(&self) -> Result<(Matrix<$type>, Matrix<$type>), LUDecompositionError> {
-                if (self.n_rows != self.n_cols) {
-                    return Err(NotSquareError);
-                }
-                let mut lu_l = Matrix::new(self.n_rows, self.n_cols);
-                let mut lu_u = Matrix::new(self.n_rows, self.n_cols);
+pub trait LU<T> {
+    fn lu(&self) -> Result<LuFactorization<T>, LUDecompositionError>;
+}
+
+impl<T> LuFactorization<T> {
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    pub fn permutation(&self) -> &Permutation {
+        &self.perm
+    }
+}
+
+impl<T> LuFactorization<T>
+where
+    T: Field,
+{
+    /// Solves `A x = rhs`. Panics if the length of `rhs` does not match the size of `A`.
+    pub fn solve(&self, rhs: &Vector<T>) -> Vector<T> {
+        if self.u.n_rows != rhs.n {
+            panic!(
+                "Linear system with incompatible dimensions: {:?} and {:?}",
+                (self.u.n_rows, self.u.n_cols),
+                rhs.n
+            );
+        }
+
+        // L y = P b, then U x = y
+        let y = fsub(&self.l, &self.perm.apply_vec(rhs));
+        bsub(&self.u, &y)
+    }
+
+    /// Solves `A x = rhs`, or fails if the length of `rhs` does not match the size of `A`.
+    pub fn try_solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, ShapeError> {
+        if self.u.n_rows != rhs.n {
+            return Err(DimensionMismatchError {
+                lhs: (self.u.n_rows, self.u.n_cols),
+                rhs: (rhs.n, 1),
+            });
+        }
+
+        Ok(self.solve(rhs))
+    }
+
+    /// Solves `A X = rhs` for every column of `rhs`.
+    pub fn solve_matrix(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        let mut x = Matrix::zeros(rhs.n_rows, rhs.n_cols);
+        for c in 0..rhs.n_cols {
+            let b = Vector::from_fn(rhs.n_rows, |i| rhs[(i, c)].clone());
+            let xc = self.solve(&b);
+            for (i, xi) in xc.into_vec().into_iter().enumerate() {
+                x[(i, c)] = xi;
+            }
+        }
+
+        x
+    }
+
+    pub fn det(&self) -> T {
+        let mut det = if self.perm.sign() < 0 {
+            -T::one()
+        } else {
+            T::one()
+        };
+        for i in 0..self.u.n_rows {
+            det *= self.u[(i, i)].clone();
+        }
+
+        det
+    }
 
-                for i in (0..self.n_rows) {
-                    lu_l[(i, i)] = 1.0;
+    pub fn inverse(&self) -> Matrix<T> {
+        self.solve_matrix(&Matrix::eye(self.u.n_rows))
+    }
+}
+
+impl<T> LuFactorization<T>
+where
+    T: ComplexField,
+{
+    /// Natural logarithm of the absolute value of the determinant, which stays finite where
+    /// `det` would overflow or underflow. For real matrices, combine with `sign` to recover the
+    /// determinant.
+    pub fn log_det(&self) -> T::Real {
+        let mut log_det = T::Real::zero();
+        for i in 0..self.u.n_rows {
+            log_det += self.u[(i, i)].modulus().ln();
+        }
+
+        log_det
+    }
+}
+
+impl<T> LuFactorization<T>
+where
+    T: RealField,
+{
+    /// Sign of the determinant, `1` or `-1`.
+    pub fn sign(&self) -> T {
+        let mut sign = if self.perm.sign() < 0 {
+            -T::one()
+        } else {
+            T::one()
+        };
+        for i in 0..self.u.n_rows {
+            if self.u[(i, i)] < T::zero() {
+                sign = -sign;
+            }
+        }
+
+        sign
+    }
+}
+
+impl<T> LU<T> for Matrix<T>
+where
+    T: Field,
+{
+    fn lu(&self) -> Result<LuFactorization<T>, LUDecompositionError> {
+        if self.n_rows != self.n_cols {
+            return Err(NotSquareError);
+        }
+
+        let n = self.n_rows;
+        let mut lu = self.clone();
+        let mut perm = Permutation::identity(n);
+
+        for k in 0..n {
+            // Pick the entry of largest magnitude on or below the diagonal as pivot
+            let mut pivot_row = k;
+            let mut pivot_abs = lu[(k, k)].magnitude();
+            for i in k + 1..n {
+                let abs = lu[(i, k)].magnitude();
+                if abs > pivot_abs {
+                    pivot_abs = abs;
+                    pivot_row = i;
                 }
+            }
+            if pivot_abs.is_zero() {
+                return Err(SingularMatrixError);
+            }
+
+            lu.swap_rows(k, pivot_row);
+            perm.swap(k, pivot_row);
 
-                for j in (0..self.n_rows) {
-                    // Forward pass
-                    for i in (0..=j) {
-                        let mut s_term = 0.0;
-                        for k in (0..i) {
-                            s_term += lu_l[(i, k)] * lu_u[(k, j)];
-                        }
-                        lu_u[(i, j)] = self[(i, j)] - s_term;
-                    }
-
-                    // Backward pass
-                    for i in (j + 1..self.n_rows) {
-                        let mut s_term = 0.0;
-                        for k in (0..i) {
-                            s_term += lu_l[(i, k)] * lu_u[(k, j)];
-                        }
-                        lu_l[(i, j)] = 1.0 / lu_u[(j, j)] * (self[(i, j)] - s_term);
-                    }
+            let pivot = lu[(k, k)].clone();
+            for i in k + 1..n {
+                let factor = lu[(i, k)].clone() / pivot.clone();
+                lu[(i, k)] = factor.clone();
+                for j in k + 1..n {
+                    let ukj = lu[(k, j)].clone();
+                    lu[(i, j)] -= factor.clone() * ukj;
                 }
+            }
+        }
 
-                Ok((lu_l, lu_u))
+        // Unpack the multipliers below the diagonal into L
+        let mut l = Matrix::<T>::eye(n);
+        for i in 0..n {
+            for j in 0..i {
+                l[(i, j)] = std::mem::replace(&mut lu[(i, j)], T::zero());
             }
         }
-    };
+
+        Ok(LuFactorization { perm, l, u: lu })
+    }
 }
 
-impl_lu!(f32);
-impl_lu!(f64);
+impl<T> LU<T> for MatrixView<'_, T>
+where
+    T: Copy,
+    Matrix<T>: LU<T>,
+{
+    fn lu(&self) -> Result<LuFactorization<T>, LUDecompositionError> {
+        self.to_matrix().lu()
+    }
+}
 
 #[cfg(test)]
 mod test {
     use crate::core::gemm::gemm;
+    use crate::linalg::solve::Solve;
 
     use super::*;
     use approx::assert_relative_eq;
 
+    #[test]
+    fn test_lu_view() {
+        let m: Matrix<f64> = matrix![9.0, 9.0, 9.0, 9.0; 9.0, 2.0, 1.0, 1.0; 9.0, 4.0, -6.0, 0.0; 9.0, -2.0, 7.0, 2.0];
+
+        let view = m.view(1.., 1..);
+        let lu = view.lu().unwrap();
+        let expected = matrix![2.0, 1.0, 1.0; 4.0, -6.0, 0.0; -2.0, 7.0, 2.0]
+            .lu()
+            .unwrap();
+        assert_eq!(lu.u().data, expected.u().data);
+        assert_relative_eq!(lu.det(), -16.0, epsilon = 1e-12);
+
+        let x = view.solve(&Vector::from_vec(&[5.0, -2.0, 9.0])).unwrap();
+        for (i, xi) in [1.0, 1.0, 2.0].iter().enumerate() {
+            assert_relative_eq!(x[i], xi, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_lu_col_major() {
+        let m: Matrix<f64> = matrix![2.0, 1.0, 1.0; 4.0, -6.0, 0.0; -2.0, 7.0, 2.0];
+        let lu = m.to_col_major().lu().unwrap();
+        let expected = m.lu().unwrap();
+
+        assert_eq!(lu.u().to_row_major().data, expected.u().data);
+        assert_eq!(lu.l().to_row_major().data, expected.l().data);
+        assert_eq!(lu.det(), expected.det());
+    }
+
     #[test]
     fn test_lu() {
-        let m: Matrix<f32> = matrix![4.0, 3.0; 6.0, 3.0];
+        let m: Matrix<f64> = matrix![2.0, 1.0, 1.0; 4.0, -6.0, 0.0; -2.0, 7.0, 2.0];
 
-        let lu = m.lu();
-        assert!(lu.is_ok());
-
-        let (lu_l, lu_u) = lu.unwrap();
-        assert_relative_eq!(lu_l[(0, 0)], 1.0);
-        assert_relative_eq!(lu_l[(1, 0)], 1.5);
-        assert_relative_eq!(lu_l[(1, 1)], 1.0);
-        assert_relative_eq!(lu_u[(0, 0)], 4.0);
-        assert_relative_eq!(lu_u[(0, 1)], 3.0);
-        assert_relative_eq!(lu_u[(1, 1)], -1.5);
-
-        let m_rec = gemm(&lu_l, &lu_u);
-        assert_eq!(m_rec.n_rows, m.n_rows);
-        assert_eq!(m_rec.n_cols, m.n_cols);
-        for i in 0..m_rec.n_rows {
-            for j in 0..m_rec.n_cols {
-                assert_relative_eq!(m_rec[(i, j)], m[(i, j)]);
+        let lu = m.lu().unwrap();
+        let perm = lu.permutation();
+        assert_eq!(perm[0], 1);
+        assert_eq!(perm.sign(), -1);
+
+        let (lu_l, lu_u) = (lu.l(), lu.u());
+        for i in 0..3 {
+            assert_relative_eq!(lu_l[(i, i)], 1.0);
+            for j in i + 1..3 {
+                assert_relative_eq!(lu_l[(i, j)], 0.0);
+                assert_relative_eq!(lu_u[(j, i)], 0.0);
+            }
+        }
+
+        let m_rec = gemm(lu_l, lu_u);
+        let pm = perm.apply_rows(&m);
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_relative_eq!(m_rec[(i, j)], pm[(i, j)], epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lu_zero_leading_pivot() {
+        let m: Matrix<f32> = matrix![0.0, 1.0; 1.0, 0.0];
+
+        let lu = m.lu().unwrap();
+        let perm = lu.permutation();
+        assert_eq!(perm[0], 1);
+        assert_eq!(perm[1], 0);
+        assert_eq!(perm.sign(), -1);
+        assert_relative_eq!(lu.u()[(0, 0)], 1.0);
+        assert_relative_eq!(lu.l()[(1, 0)], 0.0);
+        assert_relative_eq!(lu.u()[(1, 1)], 1.0);
+    }
+
+    #[test]
+    fn test_lu_solve() {
+        let m: Matrix<f64> = matrix![2.0, 1.0, 1.0; 4.0, -6.0, 0.0; -2.0, 7.0, 2.0];
+        let lu = m.lu().unwrap();
+
+        let x = lu.solve(&Vector::from_vec(&[5.0, -2.0, 9.0]));
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[2], 2.0, epsilon = 1e-12);
+
+        let rhs: Matrix<f64> = matrix![5.0, 2.0; -2.0, 4.0; 9.0, -2.0];
+        let x = lu.solve_matrix(&rhs);
+        let ax = gemm(&m, &x);
+        for i in 0..3 {
+            for j in 0..2 {
+                assert_relative_eq!(ax[(i, j)], rhs[(i, j)], epsilon = 1e-12);
             }
         }
     }
 
+    #[test]
+    fn test_lu_det_inverse() {
+        let m: Matrix<f64> = matrix![2.0, 1.0, 1.0; 4.0, -6.0, 0.0; -2.0, 7.0, 2.0];
+        let lu = m.lu().unwrap();
+
+        assert_relative_eq!(lu.det(), -16.0, epsilon = 1e-12);
+        assert_relative_eq!(lu.sign(), -1.0);
+        assert_relative_eq!(lu.log_det(), 16.0f64.ln(), epsilon = 1e-12);
+
+        let inv = lu.inverse();
+        let eye = gemm(&m, &inv);
+        for i in 0..3 {
+            for j in 0..3 {
+                let delta = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(eye[(i, j)], delta, epsilon = 1e-12);
+            }
+        }
+
+        let m: Matrix<f32> = matrix![4.0, 3.0; 6.0, 3.0];
+        let lu = m.lu().unwrap();
+        assert_relative_eq!(lu.det(), -6.0, epsilon = 1e-5);
+        assert_relative_eq!(lu.sign(), -1.0);
+    }
+
+    #[test]
+    fn test_lu_singular() {
+        let m: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0];
+
+        let lu = m.lu();
+        assert!(lu.is_err());
+        assert_eq!(lu.unwrap_err(), SingularMatrixError);
+    }
+
     #[test]
     fn test_lu_not_square() {
         let m: Matrix<f32> = matrix![1.0, 1.0, 0.0; 0.0, 1.0, 1.0];
@@ -90,4 +339,40 @@ mod test {
         assert!(lu.is_err());
         assert_eq!(lu.unwrap_err(), NotSquareError);
     }
+
+    #[test]
+    fn test_lu_complex() {
+        use crate::core::complex::Complex;
+
+        let z = |re, im| Complex::new(re, im);
+        let m: Matrix<Complex<f64>> = matrix![z(1.0, 0.0), z(0.0, 1.0); z(2.0, 1.0), z(1.0, 0.0)];
+
+        let lu = m.lu().unwrap();
+        // The second row has the larger modulus in the first column
+        assert_eq!(lu.u()[(0, 0)], z(2.0, 1.0));
+
+        let x = lu.solve(&Vector::from([z(1.0, 1.0), z(5.0, -1.0)]));
+        assert_relative_eq!(x[0].re, 1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[0].im, -1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1].re, 2.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1].im, 0.0, epsilon = 1e-12);
+
+        let det = lu.det();
+        assert_relative_eq!(det.re, 2.0, epsilon = 1e-12);
+        assert_relative_eq!(det.im, -2.0, epsilon = 1e-12);
+        assert_relative_eq!(lu.log_det(), 8.0f64.sqrt().ln(), epsilon = 1e-12);
+
+        let eye = gemm(&m, &lu.inverse());
+        for i in 0..2 {
+            for j in 0..2 {
+                let delta = if i == j { 1.0 } else { 0.0 };
+                assert_relative_eq!(eye[(i, j)].re, delta, epsilon = 1e-12);
+                assert_relative_eq!(eye[(i, j)].im, 0.0, epsilon = 1e-12);
+            }
+        }
+        assert_eq!(
+            m.solve(&Vector::from([z(1.0, 1.0), z(5.0, -1.0)])).unwrap(),
+            x
+        );
+    }
 }
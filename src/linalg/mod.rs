@@ -1,3 +1,9 @@
 pub mod chol;
+pub mod cond;
+pub mod eigen;
+pub mod inverse;
+pub mod lstsq;
 pub mod lu;
+pub mod qr;
 pub mod solve;
+pub mod svd;
@@ -0,0 +1,431 @@
+use crate::core::error::QRDecompositionError;
+use crate::core::error::QRDecompositionError::RankDeficientError;
+use crate::core::matrix::*;
+use crate::core::permutation::Permutation;
+use crate::core::vector::Vector;
+use crate::core::view::MatrixView;
+
+/// Householder QR decomposition `A = Q * R` in compact form.
+///
+/// The `i`-th Householder reflector is `H_i = I - tau_i * v_i * v_i^T`, where `v_i` has a unit
+/// entry in position `i`, zeros above it, and its remaining entries stored below the diagonal of
+/// column `i` of `qr`. `R` is stored on and above the diagonal, and `Q = H_0 * H_1 * ... * H_{k-1}`
+/// with `k = min(n_rows, n_cols)`.
+#[derive(Debug)]
+pub struct QRDecomposition<T> {
+    pub(crate) qr: Matrix<T>,
+    pub(crate) tau: Vector<T>,
+}
+
+pub trait QR<T> {
+    fn qr(&self) -> QRDecomposition<T>;
+
+    /// QR decomposition with column pivoting, `A * P = Q * R`.
+    ///
+    /// At every step the remaining column of largest norm is moved to the front, so the
+    /// magnitudes of the diagonal entries of `R` are non-increasing.
+    fn qr_pivoted(&self) -> (QRDecomposition<T>, Permutation);
+}
+
+impl<T> QRDecomposition<T> {
+    /// Householder vectors (below the diagonal) packed together with `R` (on and above it).
+    pub fn reflectors(&self) -> &Matrix<T> {
+        &self.qr
+    }
+
+    /// Scaling factors of the Householder reflectors.
+    pub fn tau(&self) -> &Vector<T> {
+        &self.tau
+    }
+}
+
+#[macro_export]
+macro_rules! impl_qr {
+    ($type:ty) => {
+        impl QR<$type> for Matrix<$type> {
+            fn qr(&self) -> QRDecomposition<$type> {
+                QRDecomposition::<$type>::factor(self, false).0
+            }
+
+            fn qr_pivoted(&self) -> (QRDecomposition<$type>, Permutation) {
+                QRDecomposition::<$type>::factor(self, true)
+            }
+        }
+
+        impl QRDecomposition<$type> {
+            fn factor(a: &Matrix<$type>, pivot: bool) -> (Self, Permutation) {
+                let (m, n) = (a.n_rows, a.n_cols);
+                let k = m.min(n);
+                let mut qr = a.clone();
+                let mut tau = Vector::new(k);
+                let mut perm = Permutation::identity(n);
+
+                for j in 0..k {
+                    if pivot {
+                        let mut best = j;
+                        let mut best_norm: $type = -1.0;
+                        for c in j..n {
+                            let mut norm = 0.0;
+                            for i in j..m {
+                                norm += qr[(i, c)] * qr[(i, c)];
+                            }
+                            if norm > best_norm {
+                                best = c;
+                                best_norm = norm;
+                            }
+                        }
+                        qr.swap_cols(j, best);
+                        perm.swap(j, best);
+                    }
+
+                    // Scale the column below the diagonal to avoid overflow in the norm
+                    let mut scale: $type = 0.0;
+                    for i in j + 1..m {
+                        scale = scale.max(qr[(i, j)].abs());
+                    }
+                    if scale == 0.0 {
+                        // Nothing to annihilate, H_j is the identity
+                        continue;
+                    }
+
+                    let alpha = qr[(j, j)];
+                    let mut sum = (alpha / scale) * (alpha / scale);
+                    for i in j + 1..m {
+                        sum += (qr[(i, j)] / scale) * (qr[(i, j)] / scale);
+                    }
+                    let beta = -alpha.signum() * scale * sum.sqrt();
+
+                    tau[j] = (beta - alpha) / beta;
+                    for i in j + 1..m {
+                        qr[(i, j)] /= alpha - beta;
+                    }
+                    qr[(j, j)] = beta;
+
+                    // Apply H_j to the trailing columns
+                    for c in j + 1..n {
+                        let mut w = qr[(j, c)];
+                        for i in j + 1..m {
+                            w += qr[(i, j)] * qr[(i, c)];
+                        }
+                        w *= tau[j];
+
+                        qr[(j, c)] -= w;
+                        for i in j + 1..m {
+                            let vi = qr[(i, j)];
+                            qr[(i, c)] -= w * vi;
+                        }
+                    }
+                }
+
+                (QRDecomposition { qr, tau }, perm)
+            }
+
+            /// Applies the reflector `H_j` to column `c` of `m` in place.
+            fn reflect(&self, j: usize, m: &mut Matrix<$type>, c: usize) {
+                let mut w = m[(j, c)];
+                for i in j + 1..self.qr.n_rows {
+                    w += self.qr[(i, j)] * m[(i, c)];
+                }
+                w *= self.tau[j];
+
+                m[(j, c)] -= w;
+                for i in j + 1..self.qr.n_rows {
+                    m[(i, c)] -= w * self.qr[(i, j)];
+                }
+            }
+
+            fn check_rows(&self, n_rows: usize) {
+                if n_rows != self.qr.n_rows {
+                    panic!(
+                        "Cannot apply Q of size {:?} to operand with {} rows",
+                        (self.qr.n_rows, self.qr.n_rows),
+                        n_rows
+                    );
+                }
+            }
+
+            /// Computes `Q * rhs` without forming `Q`.
+            pub fn apply_q_matrix(&self, rhs: &Matrix<$type>) -> Matrix<$type> {
+                self.check_rows(rhs.n_rows);
+
+                let mut result = rhs.clone();
+                for c in 0..result.n_cols {
+                    for j in (0..self.tau.n).rev() {
+                        self.reflect(j, &mut result, c);
+                    }
+                }
+
+                result
+            }
+
+            /// Computes `Q^T * rhs` without forming `Q`.
+            pub fn apply_qt_matrix(&self, rhs: &Matrix<$type>) -> Matrix<$type> {
+                self.check_rows(rhs.n_rows);
+
+                let mut result = rhs.clone();
+                for c in 0..result.n_cols {
+                    for j in 0..self.tau.n {
+                        self.reflect(j, &mut result, c);
+                    }
+                }
+
+                result
+            }
+
+            /// Computes `Q * rhs` without forming `Q`.
+            pub fn apply_q(&self, rhs: &Vector<$type>) -> Vector<$type> {
+                let rhs = Matrix {
+                    n_rows: rhs.n,
+                    n_cols: 1,
+                    data: rhs.data.clone(),
+                    layout: Layout::RowMajor,
+                };
+                let result = self.apply_q_matrix(&rhs);
+
+                Vector {
+                    n: result.n_rows,
+                    data: result.data,
+                }
+            }
+
+            /// Computes `Q^T * rhs` without forming `Q`.
+            pub fn apply_qt(&self, rhs: &Vector<$type>) -> Vector<$type> {
+                let rhs = Matrix {
+                    n_rows: rhs.n,
+                    n_cols: 1,
+                    data: rhs.data.clone(),
+                    layout: Layout::RowMajor,
+                };
+                let result = self.apply_qt_matrix(&rhs);
+
+                Vector {
+                    n: result.n_rows,
+                    data: result.data,
+                }
+            }
+
+            /// Thin factor `Q` of size `n_rows x min(n_rows, n_cols)`.
+            pub fn q(&self) -> Matrix<$type> {
+                let (m, k) = (self.qr.n_rows, self.tau.n);
+                let mut eye = Matrix::<$type>::zeros(m, k);
+                for i in 0..k {
+                    eye[(i, i)] = 1.0;
+                }
+
+                self.apply_q_matrix(&eye)
+            }
+
+            /// Full orthogonal factor `Q` of size `n_rows x n_rows`.
+            pub fn q_full(&self) -> Matrix<$type> {
+                self.apply_q_matrix(&Matrix::<$type>::eye(self.qr.n_rows))
+            }
+
+            /// Thin factor `R` of size `min(n_rows, n_cols) x n_cols`.
+            pub fn r(&self) -> Matrix<$type> {
+                let (k, n) = (self.tau.n, self.qr.n_cols);
+                let mut r = Matrix::<$type>::zeros(k, n);
+                for i in 0..k {
+                    for j in i..n {
+                        r[(i, j)] = self.qr[(i, j)];
+                    }
+                }
+
+                r
+            }
+
+            /// Full factor `R` of size `n_rows x n_cols`.
+            pub fn r_full(&self) -> Matrix<$type> {
+                let (m, n) = (self.qr.n_rows, self.qr.n_cols);
+                let mut r = Matrix::<$type>::zeros(m, n);
+                for i in 0..m.min(n) {
+                    for j in i..n {
+                        r[(i, j)] = self.qr[(i, j)];
+                    }
+                }
+
+                r
+            }
+
+            /// Solves `A x = rhs` in the least-squares sense for `A` with full column rank.
+            pub fn solve(
+                &self,
+                rhs: &Vector<$type>,
+            ) -> Result<Vector<$type>, QRDecompositionError> {
+                let (m, n) = (self.qr.n_rows, self.qr.n_cols);
+                if m < n {
+                    return Err(RankDeficientError);
+                }
+
+                // Diagonal entries of R negligible relative to ||R||_F = ||A||_F count as zero
+                let mut r_norm: $type = 0.0;
+                for i in 0..n {
+                    for j in i..n {
+                        r_norm += self.qr[(i, j)] * self.qr[(i, j)];
+                    }
+                }
+                let tol = r_norm.sqrt() * (m as $type) * <$type>::EPSILON;
+                for i in 0..n {
+                    if self.qr[(i, i)].abs() <= tol {
+                        return Err(RankDeficientError);
+                    }
+                }
+
+                let qtb = self.apply_qt(rhs);
+                let mut x = Vector::new(n);
+                for i in (0..n).rev() {
+                    let mut s = qtb[i];
+                    for j in i + 1..n {
+                        s -= self.qr[(i, j)] * x[j];
+                    }
+                    x[i] = s / self.qr[(i, i)];
+                }
+
+                Ok(x)
+            }
+        }
+    };
+}
+
+impl_qr!(f32);
+impl_qr!(f64);
+
+impl<T> QR<T> for MatrixView<'_, T>
+where
+    T: Copy,
+    Matrix<T>: QR<T>,
+{
+    fn qr(&self) -> QRDecomposition<T> {
+        self.to_matrix().qr()
+    }
+
+    fn qr_pivoted(&self) -> (QRDecomposition<T>, Permutation) {
+        self.to_matrix().qr_pivoted()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::gemm::{gemm, gemv};
+
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn assert_matrix_eq(a: &Matrix<f64>, b: &Matrix<f64>) {
+        assert_eq!(a.n_rows, b.n_rows);
+        assert_eq!(a.n_cols, b.n_cols);
+        for i in 0..a.n_rows {
+            for j in 0..a.n_cols {
+                assert_relative_eq!(a[(i, j)], b[(i, j)], epsilon = 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_qr_thin() {
+        let m: Matrix<f64> =
+            matrix![12.0, -51.0, 4.0; 6.0, 167.0, -68.0; -4.0, 24.0, -41.0; 1.0, 2.0, 3.0];
+
+        let qr = m.qr();
+        let q = qr.q();
+        let r = qr.r();
+        assert_eq!((q.n_rows, q.n_cols), (4, 3));
+        assert_eq!((r.n_rows, r.n_cols), (3, 3));
+        for i in 0..3 {
+            for j in 0..i {
+                assert_eq!(r[(i, j)], 0.0);
+            }
+        }
+
+        assert_matrix_eq(&gemm(&q, &r), &m);
+        assert_matrix_eq(&gemm(&q.transpose(), &q), &Matrix::<f64>::eye(3));
+    }
+
+    #[test]
+    fn test_qr_full() {
+        let m: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+
+        let qr = m.qr();
+        let q = qr.q_full();
+        let r = qr.r_full();
+        assert_eq!((q.n_rows, q.n_cols), (3, 3));
+        assert_eq!((r.n_rows, r.n_cols), (3, 2));
+
+        assert_matrix_eq(&gemm(&q, &r), &m);
+        assert_matrix_eq(&gemm(&q.transpose(), &q), &Matrix::<f64>::eye(3));
+        assert_matrix_eq(&gemm(&q, &q.transpose()), &Matrix::<f64>::eye(3));
+    }
+
+    #[test]
+    fn test_qr_wide() {
+        let m: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+
+        let qr = m.qr();
+        let q = qr.q();
+        let r = qr.r();
+        assert_eq!((q.n_rows, q.n_cols), (2, 2));
+        assert_eq!((r.n_rows, r.n_cols), (2, 3));
+        assert_matrix_eq(&gemm(&q, &r), &m);
+    }
+
+    #[test]
+    fn test_apply_q() {
+        let m: Matrix<f64> =
+            matrix![2.0, -1.0, 0.0; -1.0, 2.0, -1.0; 0.0, -1.0, 2.0; 1.0, 1.0, 1.0];
+        let v = Vector::from_vec(&[1.0, -2.0, 3.0, 0.5]);
+
+        let qr = m.qr();
+        let q = qr.q_full();
+
+        let qv = qr.apply_q(&v);
+        let qv_ref = gemv(&q, &v);
+        let qtv = qr.apply_qt(&v);
+        let qtv_ref = gemv(&q.transpose(), &v);
+        for i in 0..4 {
+            assert_relative_eq!(qv[i], qv_ref[i], epsilon = 1e-12);
+            assert_relative_eq!(qtv[i], qtv_ref[i], epsilon = 1e-12);
+        }
+
+        let back = qr.apply_qt_matrix(&qr.apply_q_matrix(&m));
+        assert_matrix_eq(&back, &m);
+    }
+
+    #[test]
+    fn test_qr_pivoted() {
+        let m: Matrix<f64> = matrix![1.0, 10.0, 2.0; 2.0, 0.0, 4.0; 3.0, 1.0, 6.0; 4.0, -2.0, 8.0];
+
+        let (qr, perm) = m.qr_pivoted();
+        assert_eq!(perm[0], 2);
+
+        let r = qr.r();
+        assert!(r[(0, 0)].abs() >= r[(1, 1)].abs());
+        assert!(r[(1, 1)].abs() >= r[(2, 2)].abs());
+        assert_relative_eq!(r[(2, 2)], 0.0, epsilon = 1e-12);
+
+        let mp = perm.apply_rows(&m.transpose()).transpose();
+        assert_matrix_eq(&gemm(&qr.q(), &r), &mp);
+    }
+
+    #[test]
+    fn test_qr_solve() {
+        let m: Matrix<f32> = matrix![4.0, 3.0; 6.0, 3.0];
+        let b = Vector::from_vec(&[10.0, 12.0]);
+
+        let x = m.qr().solve(&b).unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_qr_rank_deficient() {
+        let m: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0; 3.0, 6.0];
+        let b = Vector::from_vec(&[1.0, 1.0, 1.0]);
+
+        let x = m.qr().solve(&b);
+        assert!(x.is_err());
+        assert_eq!(x.unwrap_err(), RankDeficientError);
+
+        let m: Matrix<f64> = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0];
+        let b = Vector::from_vec(&[1.0, 1.0]);
+        assert_eq!(m.qr().solve(&b).unwrap_err(), RankDeficientError);
+    }
+}
@@ -1,99 +1,200 @@
-use std::ops::{Div, DivAssign, Mul, SubAssign};
-
-use crate::core::error::LUDecompositionError;
+use crate::core::error::ShapeError::{DimensionMismatchError, NotTriangularError};
+use crate::core::error::{Error, ShapeError};
 use crate::core::matrix::Matrix;
+#[cfg(feature = "rational")]
+use crate::core::rational::Rational;
+use crate::core::scalar::{ComplexField, Field};
 use crate::core::vector::Vector;
+use crate::core::view::MatrixView;
 use crate::linalg::chol::Cholesky;
 
 use super::lu::LU;
 
 pub trait Solve<T> {
-    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, LUDecompositionError>;
+    /// Solves `self * x = rhs`, or fails if `self` is singular or not square, or if the length
+    /// of `rhs` does not match its size.
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, Error>;
 }
 
-fn bsub<T>(lhs: &Matrix<T>, rhs: &Vector<T>) -> Vector<T>
+fn is_upper_triangular<T>(m: &Matrix<T>) -> bool
 where
-    T: Copy
-        + Clone
-        + Default
-        + Mul<Output = T>
-        + Div<Output = T>
-        + DivAssign
-        + SubAssign
-        + PartialEq,
+    T: Field,
 {
-    // Check that lhs is upper triangular
-    for i in 0..lhs.n_rows {
-        for j in 0..i {
-            if lhs[(i, j)] != T::default() {
-                panic!("Back-substitution matrix is not upper triangular");
-            }
-        }
+    (0..m.n_rows).all(|i| (0..i.min(m.n_cols)).all(|j| m[(i, j)] == T::zero()))
+}
+
+fn is_lower_triangular<T>(m: &Matrix<T>) -> bool
+where
+    T: Field,
+{
+    (0..m.n_rows).all(|i| (i + 1..m.n_cols).all(|j| m[(i, j)] == T::zero()))
+}
+
+/// Checks that `lhs` is square, of the same size as `rhs`, and triangular as told by `triangular`.
+fn check_triangular<T>(
+    lhs: &Matrix<T>,
+    rhs: &Vector<T>,
+    triangular: fn(&Matrix<T>) -> bool,
+) -> Result<(), ShapeError>
+where
+    T: Field,
+{
+    if lhs.n_rows != lhs.n_cols || lhs.n_cols != rhs.n {
+        return Err(DimensionMismatchError {
+            lhs: (lhs.n_rows, lhs.n_cols),
+            rhs: (rhs.n, 1),
+        });
+    }
+    if !triangular(lhs) {
+        return Err(NotTriangularError);
     }
 
-    let mut result = Vector::from_vec(&rhs.data);
+    Ok(())
+}
+
+pub(crate) fn bsub<T>(lhs: &Matrix<T>, rhs: &Vector<T>) -> Vector<T>
+where
+    T: Field,
+{
+    if !is_upper_triangular(lhs) {
+        panic!("Back-substitution matrix is not upper triangular");
+    }
+
+    backward(lhs, rhs)
+}
+
+/// Solves `lhs * x = rhs` for upper triangular `lhs` by back substitution, or fails if `lhs` is
+/// not square and upper triangular or its size does not match `rhs`.
+pub fn try_bsub<T>(lhs: &Matrix<T>, rhs: &Vector<T>) -> Result<Vector<T>, ShapeError>
+where
+    T: Field,
+{
+    check_triangular(lhs, rhs, is_upper_triangular)?;
+
+    Ok(backward(lhs, rhs))
+}
+
+fn backward<T>(lhs: &Matrix<T>, rhs: &Vector<T>) -> Vector<T>
+where
+    T: Field,
+{
+    let mut result = rhs.clone();
     for i in (0..rhs.n).rev() {
-        result[i] /= lhs[(i, i)];
+        result[i] /= lhs[(i, i)].clone();
 
-        let ri = result[i];
+        let ri = result[i].clone();
         for j in (0..i).rev() {
-            result[j] -= ri * lhs[(j, i)] / lhs[(i, i)];
+            result[j] -= ri.clone() * lhs[(j, i)].clone();
         }
     }
 
     result
 }
 
-fn fsub<T>(lhs: &Matrix<T>, rhs: &Vector<T>) -> Vector<T>
+pub(crate) fn fsub<T>(lhs: &Matrix<T>, rhs: &Vector<T>) -> Vector<T>
 where
-    T: Copy
-        + Clone
-        + Default
-        + Mul<Output = T>
-        + Div<Output = T>
-        + DivAssign
-        + SubAssign
-        + PartialEq,
+    T: Field,
 {
-    // Check that lhs is lower triangular
-    for i in 0..lhs.n_rows {
-        for j in i + 1..lhs.n_rows {
-            if lhs[(i, j)] != T::default() {
-                panic!("Forward-substitution matrix is not lower triangular");
-            }
-        }
+    if !is_lower_triangular(lhs) {
+        panic!("Forward-substitution matrix is not lower triangular");
     }
 
-    let mut result = Vector::from_vec(&rhs.data);
+    forward(lhs, rhs)
+}
+
+/// Solves `lhs * x = rhs` for lower triangular `lhs` by forward substitution, or fails if `lhs`
+/// is not square and lower triangular or its size does not match `rhs`.
+pub fn try_fsub<T>(lhs: &Matrix<T>, rhs: &Vector<T>) -> Result<Vector<T>, ShapeError>
+where
+    T: Field,
+{
+    check_triangular(lhs, rhs, is_lower_triangular)?;
+
+    Ok(forward(lhs, rhs))
+}
+
+fn forward<T>(lhs: &Matrix<T>, rhs: &Vector<T>) -> Vector<T>
+where
+    T: Field,
+{
+    let mut result = rhs.clone();
     for i in 0..rhs.n {
-        result[i] /= lhs[(i, i)];
+        result[i] /= lhs[(i, i)].clone();
 
-        let ri = result[i];
+        let ri = result[i].clone();
         for j in i + 1..rhs.n {
-            result[j] -= ri * lhs[(j, i)] / lhs[(i, i)];
+            result[j] -= ri.clone() * lhs[(j, i)].clone();
         }
     }
 
     result
 }
 
-impl Solve<f32> for Matrix<f32> {
-    fn solve(&self, rhs: &Vector<f32>) -> Result<Vector<f32>, LUDecompositionError> {
-        let (l, u) = if let Ok(chol) = self.chol() {
-            (chol.transpose(), chol)
-        } else {
-            self.lu()?
-        };
+/// Fields whose matrices `Solve` can factorize, and how.
+///
+/// The provided method goes through LU decomposition, which works over any field, so a custom
+/// `Field` only needs an empty impl. Real and complex fields first try the Cholesky
+/// decomposition, which is twice as fast on Hermitian positive definite matrices.
+pub trait SolveField: Field {
+    /// Solves `a x = b` for a square `a` of the same size as `b`.
+    fn solve_square(a: &Matrix<Self>, b: &Vector<Self>) -> Result<Vector<Self>, Error> {
+        Ok(a.lu()?.solve(b))
+    }
+}
 
-        let y = bsub(&u, rhs);
-        Ok(fsub(&l, &y))
+impl<T> SolveField for T
+where
+    T: ComplexField,
+{
+    fn solve_square(a: &Matrix<T>, b: &Vector<T>) -> Result<Vector<T>, Error> {
+        if let Ok(chol) = a.chol() {
+            return Ok(chol.solve(b));
+        }
+
+        Ok(a.lu()?.solve(b))
+    }
+}
+
+#[cfg(feature = "rational")]
+impl SolveField for Rational {}
+
+impl<T> Solve<T> for Matrix<T>
+where
+    T: SolveField,
+{
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, Error> {
+        if self.n_rows != rhs.n {
+            return Err(DimensionMismatchError {
+                lhs: (self.n_rows, self.n_cols),
+                rhs: (rhs.n, 1),
+            }
+            .into());
+        }
+
+        T::solve_square(self, rhs)
+    }
+}
+
+impl<T> Solve<T> for MatrixView<'_, T>
+where
+    T: Copy,
+    Matrix<T>: Solve<T>,
+{
+    fn solve(&self, rhs: &Vector<T>) -> Result<Vector<T>, Error> {
+        self.to_matrix().solve(rhs)
     }
 }
 
 #[cfg(test)]
 mod test {
     use approx::assert_relative_eq;
+    use proptest::prelude::*;
 
+    use crate::core::error::LUDecompositionError::SingularMatrixError;
+    use crate::core::error::{CholDecompositionError, Error};
+    use crate::core::gemm::{gemm, gemv, try_dot, try_gemv};
+    use crate::core::matrix::Layout;
+    use crate::linalg::chol::Cholesky;
     use crate::matrix;
 
     use super::*;
@@ -102,7 +203,7 @@ mod test {
     #[should_panic]
     fn test_bsub_panic() {
         let lhs = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0];
-        let b = Vector::from_vec(&vec![1.0, 1.0, 1.0]);
+        let b = Vector::from_vec(&[1.0, 1.0, 1.0]);
 
         bsub(&lhs, &b);
     }
@@ -110,7 +211,7 @@ mod test {
     #[test]
     fn test_bsub() {
         let lhs = matrix![1.0, 1.0, 1.0; 0.0, 1.0, 2.0; 0.0, 0.0, 1.0];
-        let b = Vector::from_vec(&vec![1.0, 1.0, 1.0]);
+        let b = Vector::from_vec(&[1.0, 1.0, 1.0]);
 
         let y = bsub(&lhs, &b);
 
@@ -123,7 +224,7 @@ mod test {
     #[should_panic]
     fn test_fsub_panic() {
         let lhs = matrix![1.0, 2.0, 3.0; 4.0, 5.0, 6.0; 7.0, 8.0, 9.0];
-        let b = Vector::from_vec(&vec![1.0, 1.0, 1.0]);
+        let b = Vector::from_vec(&[1.0, 1.0, 1.0]);
 
         fsub(&lhs, &b);
     }
@@ -131,7 +232,7 @@ mod test {
     #[test]
     fn test_fsub() {
         let lhs = matrix![1.0, 0.0, 0.0; 2.0, 1.0, 0.0; 1.0, 1.0, 1.0];
-        let b = Vector::from_vec(&vec![1.0, 1.0, 1.0]);
+        let b = Vector::from_vec(&[1.0, 1.0, 1.0]);
 
         let y = fsub(&lhs, &b);
 
@@ -139,4 +240,199 @@ mod test {
         assert_relative_eq!(y[1], -1.0);
         assert_relative_eq!(y[2], 1.0);
     }
+
+    #[test]
+    fn test_substitution_non_unit_diagonal() {
+        let upper = matrix![2.0, 1.0; 0.0, 4.0];
+        let lower = upper.transpose();
+        let b = Vector::from_vec(&[4.0, 8.0]);
+
+        let x = bsub(&upper, &b);
+        assert_relative_eq!(x[0], 1.0);
+        assert_relative_eq!(x[1], 2.0);
+
+        let x = fsub(&lower, &b);
+        assert_relative_eq!(x[0], 2.0);
+        assert_relative_eq!(x[1], 1.5);
+    }
+
+    #[test]
+    fn test_try_substitution() {
+        let upper = matrix![2.0, 1.0; 0.0, 4.0];
+        let b = Vector::from_vec(&[4.0, 8.0]);
+
+        let x = try_bsub(&upper, &b).unwrap();
+        assert_relative_eq!(x[0], 1.0);
+        assert_relative_eq!(x[1], 2.0);
+        let x = try_fsub(&upper.transpose(), &b).unwrap();
+        assert_relative_eq!(x[0], 2.0);
+        assert_relative_eq!(x[1], 1.5);
+
+        assert_eq!(try_fsub(&upper, &b).unwrap_err(), NotTriangularError);
+        assert_eq!(
+            try_bsub(&upper.transpose(), &b).unwrap_err(),
+            NotTriangularError
+        );
+        assert_eq!(
+            try_bsub(&upper, &Vector::from_vec(&[1.0])).unwrap_err(),
+            DimensionMismatchError {
+                lhs: (2, 2),
+                rhs: (1, 1)
+            }
+        );
+    }
+
+    #[test]
+    fn test_unified_error() {
+        // Residual of x for an SPD system, mixing decomposition and shape errors
+        fn residual(a: &Matrix<f64>, x: &Vector<f64>, b: &Vector<f64>) -> Result<f64, Error> {
+            a.chol()?;
+            let r = try_gemv(a, x)?.try_sub(b)?;
+
+            Ok(try_dot(&r, &r)?.sqrt())
+        }
+
+        let a = matrix![4.0, 2.0; 2.0, 3.0];
+        let b = Vector::from_vec(&[2.0, 1.0]);
+        let x = a.solve(&b).unwrap();
+        assert!(residual(&a, &x, &b).unwrap() < 1e-12);
+
+        let err = residual(&matrix![1.0, 2.0; 2.0, 1.0], &x, &b);
+        assert_eq!(
+            err.unwrap_err(),
+            Error::CholDecompositionError(CholDecompositionError::NotPositiveDefiniteError)
+        );
+        let err = residual(&a, &x, &Vector::from_vec(&[1.0]));
+        assert!(matches!(err, Err(Error::ShapeError(_))));
+    }
+
+    #[test]
+    fn test_solve() {
+        // Not symmetric, and the leading entry forces a row interchange
+        let a: Matrix<f64> = matrix![0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 3.0, 0.0, 1.0];
+        let b = Vector::from_vec(&[5.0, 3.0, 4.0]);
+
+        let x = a.solve(&b).unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(x[2], 1.0, epsilon = 1e-12);
+
+        let a: Matrix<f32> = matrix![0.0, 2.0, 1.0; 1.0, 1.0, 0.0; 3.0, 0.0, 1.0];
+        let b = Vector::from_vec(&[5.0, 3.0, 4.0]);
+
+        let x = a.solve(&b).unwrap();
+        assert_relative_eq!(x[0], 1.0, epsilon = 1e-5);
+        assert_relative_eq!(x[1], 2.0, epsilon = 1e-5);
+        assert_relative_eq!(x[2], 1.0, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn test_solve_spd() {
+        let a: Matrix<f64> = matrix![4.0, 12.0, -16.0; 12.0, 37.0, -43.0; -16.0, -43.0, 98.0];
+        let b = Vector::from_vec(&[0.0, 6.0, 39.0]);
+
+        let x = a.solve(&b).unwrap();
+        let ax = gemv(&a, &x);
+        for i in 0..3 {
+            assert_relative_eq!(ax[i], b[i], epsilon = 1e-10);
+        }
+    }
+
+    #[test]
+    fn test_solve_singular() {
+        let a: Matrix<f64> = matrix![1.0, 2.0; 2.0, 4.0];
+        let b = Vector::from_vec(&[1.0, 1.0]);
+
+        assert_eq!(a.solve(&b).unwrap_err(), Error::from(SingularMatrixError));
+    }
+
+    #[test]
+    fn test_solve_dimension_mismatch() {
+        let b = Vector::from_vec(&[1.0, 1.0, 1.0]);
+        let err = || DimensionMismatchError {
+            lhs: (2, 2),
+            rhs: (3, 1),
+        };
+
+        // Through both the Cholesky and the LU paths
+        let spd: Matrix<f64> = matrix![4.0, 2.0; 2.0, 3.0];
+        assert_eq!(spd.solve(&b).unwrap_err(), Error::from(err()));
+        assert_eq!(spd.chol().unwrap().try_solve(&b).unwrap_err(), err());
+        let a: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0];
+        assert_eq!(a.solve(&b).unwrap_err(), Error::from(err()));
+        assert_eq!(a.lu().unwrap().try_solve(&b).unwrap_err(), err());
+
+        let x = spd
+            .chol()
+            .unwrap()
+            .try_solve(&Vector::from_vec(&[2.0, 1.0]));
+        assert_relative_eq!(x.unwrap()[0], 0.5, epsilon = 1e-12);
+    }
+
+    /// Random order `n` together with `n * n` matrix entries and a right-hand side.
+    fn system() -> impl Strategy<Value = (usize, Vec<f64>, Vec<f64>)> {
+        (1usize..10).prop_flat_map(|n| {
+            (
+                Just(n),
+                prop::collection::vec(-1.0..1.0, n * n),
+                prop::collection::vec(-10.0..10.0, n),
+            )
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn prop_solve_residual_f64((n, entries, b) in system()) {
+            // Strict diagonal dominance keeps the matrix well conditioned
+            let mut a = Matrix { n_rows: n, n_cols: n, data: entries, layout: Layout::RowMajor };
+            for i in 0..n {
+                a[(i, i)] += if a[(i, i)] >= 0.0 { n as f64 } else { -(n as f64) };
+            }
+            let b = Vector::from_vec(&b);
+
+            let x = a.solve(&b).unwrap();
+            let ax = gemv(&a, &x);
+            for i in 0..n {
+                prop_assert!((ax[i] - b[i]).abs() <= 1e-10 * (1.0 + b[i].abs()));
+            }
+        }
+
+        #[test]
+        fn prop_solve_residual_spd_f64((n, entries, b) in system()) {
+            // B^T B + n I is symmetric positive definite, so this takes the Cholesky path
+            let m = Matrix { n_rows: n, n_cols: n, data: entries, layout: Layout::RowMajor };
+            let mut a = gemm(&m.transpose(), &m);
+            for i in 0..n {
+                a[(i, i)] += n as f64;
+            }
+            let b = Vector::from_vec(&b);
+
+            let x = a.solve(&b).unwrap();
+            let ax = gemv(&a, &x);
+            for i in 0..n {
+                prop_assert!((ax[i] - b[i]).abs() <= 1e-10 * (1.0 + b[i].abs()));
+            }
+        }
+
+        #[test]
+        fn prop_solve_residual_f32((n, entries, b) in system()) {
+            let mut a = Matrix {
+                n_rows: n,
+                n_cols: n,
+                data: entries.iter().map(|&x| x as f32).collect(),
+                layout: Layout::RowMajor,
+            };
+            for i in 0..n {
+                a[(i, i)] += if a[(i, i)] >= 0.0 { n as f32 } else { -(n as f32) };
+            }
+            let b: Vec<f32> = b.iter().map(|&x| x as f32).collect();
+            let b = Vector::from_vec(&b);
+
+            let x = a.solve(&b).unwrap();
+            let ax = gemv(&a, &x);
+            for i in 0..n {
+                prop_assert!((ax[i] - b[i]).abs() <= 1e-4 * (1.0 + b[i].abs()));
+            }
+        }
+    }
 }
@@ -0,0 +1,595 @@
+use std::cmp::Ordering;
+
+use crate::core::error::SVDecompositionError;
+use crate::core::error::SVDecompositionError::NoConvergenceError;
+use crate::core::matrix::*;
+use crate::core::vector::Vector;
+use crate::core::view::MatrixView;
+use crate::linalg::qr::QR;
+
+/// Which factors to compute for an `n_rows x n_cols` matrix with `k = min(n_rows, n_cols)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SVDMode {
+    /// `U` is `n_rows x k` and `V^T` is `k x n_cols`.
+    Thin,
+    /// `U` is `n_rows x n_rows` and `V^T` is `n_cols x n_cols`.
+    Full,
+}
+
+/// Singular value decomposition `A = U * diag(s) * V^T`, with singular values sorted in
+/// decreasing order.
+#[derive(Debug)]
+pub struct SVDecomposition<T> {
+    pub(crate) u: Matrix<T>,
+    pub(crate) s: Vector<T>,
+    pub(crate) vt: Matrix<T>,
+}
+
+pub trait SVD<T> {
+    fn svd(&self, mode: SVDMode) -> Result<SVDecomposition<T>, SVDecompositionError>;
+}
+
+impl<T> SVDecomposition<T> {
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    /// Singular values, largest first.
+    pub fn s(&self) -> &Vector<T> {
+        &self.s
+    }
+
+    pub fn vt(&self) -> &Matrix<T> {
+        &self.vt
+    }
+}
+
+#[macro_export]
+macro_rules! impl_svd {
+    ($type:ty) => {
+        impl SVD<$type> for Matrix<$type> {
+            fn svd(&self, mode: SVDMode) -> Result<SVDecomposition<$type>, SVDecompositionError> {
+                // The bidiagonalization below assumes n_rows >= n_cols, so wide matrices are
+                // decomposed through their transpose, A^T = U S V^T => A = V S U^T
+                let transposed = self.n_rows < self.n_cols;
+                let mut u = if transposed {
+                    self.transpose()
+                } else {
+                    self.clone()
+                };
+                let mut s = Vector::new(u.n_cols);
+                let mut v = Matrix::<$type>::zeros(u.n_cols, u.n_cols);
+
+                SVDecomposition::<$type>::decompose(&mut u, &mut s, &mut v)?;
+                SVDecomposition::<$type>::reorder(&mut u, &mut s, &mut v);
+                if mode == SVDMode::Full {
+                    u = SVDecomposition::<$type>::complete_basis(&u);
+                }
+
+                let (u, vt) = if transposed {
+                    (v, u.transpose())
+                } else {
+                    (u, v.transpose())
+                };
+
+                Ok(SVDecomposition { u, s, vt })
+            }
+        }
+
+        impl SVDecomposition<$type> {
+            /// Golub-Kahan-Reinsch SVD of an `m x n` matrix with `m >= n`, as in Numerical
+            /// Recipes. On exit `u` holds the left singular vectors, `w` the (unsorted) singular
+            /// values and `v` the right singular vectors.
+            fn decompose(
+                u: &mut Matrix<$type>,
+                w: &mut Vector<$type>,
+                v: &mut Matrix<$type>,
+            ) -> Result<(), SVDecompositionError> {
+                const MAX_ITERATIONS: usize = 30;
+
+                let (m, n) = (u.n_rows, u.n_cols);
+                let eps = <$type>::EPSILON;
+                let sign = |a: $type, b: $type| if b >= 0.0 { a.abs() } else { -a.abs() };
+
+                let mut rv1: Vec<$type> = vec![0.0; n];
+                let mut g: $type = 0.0;
+                let mut scale: $type = 0.0;
+                let mut anorm: $type = 0.0;
+                let mut l = 0;
+
+                // Householder reduction to bidiagonal form
+                for i in 0..n {
+                    l = i + 1;
+                    rv1[i] = scale * g;
+                    g = 0.0;
+                    scale = 0.0;
+                    if i < m {
+                        for k in i..m {
+                            scale += u[(k, i)].abs();
+                        }
+                        if scale != 0.0 {
+                            let mut s = 0.0;
+                            for k in i..m {
+                                u[(k, i)] /= scale;
+                                s += u[(k, i)] * u[(k, i)];
+                            }
+                            let f = u[(i, i)];
+                            g = -sign(s.sqrt(), f);
+                            let h = f * g - s;
+                            u[(i, i)] = f - g;
+                            for j in l..n {
+                                let mut s = 0.0;
+                                for k in i..m {
+                                    s += u[(k, i)] * u[(k, j)];
+                                }
+                                let f = s / h;
+                                for k in i..m {
+                                    let uki = u[(k, i)];
+                                    u[(k, j)] += f * uki;
+                                }
+                            }
+                            for k in i..m {
+                                u[(k, i)] *= scale;
+                            }
+                        }
+                    }
+                    w[i] = scale * g;
+
+                    g = 0.0;
+                    scale = 0.0;
+                    if i < m && i + 1 != n {
+                        for k in l..n {
+                            scale += u[(i, k)].abs();
+                        }
+                        if scale != 0.0 {
+                            let mut s = 0.0;
+                            for k in l..n {
+                                u[(i, k)] /= scale;
+                                s += u[(i, k)] * u[(i, k)];
+                            }
+                            let f = u[(i, l)];
+                            g = -sign(s.sqrt(), f);
+                            let h = f * g - s;
+                            u[(i, l)] = f - g;
+                            for k in l..n {
+                                rv1[k] = u[(i, k)] / h;
+                            }
+                            for j in l..m {
+                                let mut s = 0.0;
+                                for k in l..n {
+                                    s += u[(j, k)] * u[(i, k)];
+                                }
+                                for k in l..n {
+                                    u[(j, k)] += s * rv1[k];
+                                }
+                            }
+                            for k in l..n {
+                                u[(i, k)] *= scale;
+                            }
+                        }
+                    }
+                    anorm = anorm.max(w[i].abs() + rv1[i].abs());
+                }
+
+                // Accumulation of right-hand transformations
+                for i in (0..n).rev() {
+                    if i < n - 1 {
+                        if g != 0.0 {
+                            // Double division avoids possible underflow
+                            for j in l..n {
+                                v[(j, i)] = (u[(i, j)] / u[(i, l)]) / g;
+                            }
+                            for j in l..n {
+                                let mut s = 0.0;
+                                for k in l..n {
+                                    s += u[(i, k)] * v[(k, j)];
+                                }
+                                for k in l..n {
+                                    let vki = v[(k, i)];
+                                    v[(k, j)] += s * vki;
+                                }
+                            }
+                        }
+                        for j in l..n {
+                            v[(i, j)] = 0.0;
+                            v[(j, i)] = 0.0;
+                        }
+                    }
+                    v[(i, i)] = 1.0;
+                    g = rv1[i];
+                    l = i;
+                }
+
+                // Accumulation of left-hand transformations
+                for i in (0..m.min(n)).rev() {
+                    l = i + 1;
+                    g = w[i];
+                    for j in l..n {
+                        u[(i, j)] = 0.0;
+                    }
+                    if g != 0.0 {
+                        g = 1.0 / g;
+                        for j in l..n {
+                            let mut s = 0.0;
+                            for k in l..m {
+                                s += u[(k, i)] * u[(k, j)];
+                            }
+                            let f = (s / u[(i, i)]) * g;
+                            for k in i..m {
+                                let uki = u[(k, i)];
+                                u[(k, j)] += f * uki;
+                            }
+                        }
+                        for j in i..m {
+                            u[(j, i)] *= g;
+                        }
+                    } else {
+                        for j in i..m {
+                            u[(j, i)] = 0.0;
+                        }
+                    }
+                    u[(i, i)] += 1.0;
+                }
+
+                // Diagonalization of the bidiagonal form by implicit QR
+                for k in (0..n).rev() {
+                    for its in 0..MAX_ITERATIONS {
+                        // Test for splitting
+                        let mut flag = true;
+                        let mut l = k;
+                        let mut nm = 0;
+                        loop {
+                            if l == 0 || rv1[l].abs() <= eps * anorm {
+                                flag = false;
+                                break;
+                            }
+                            nm = l - 1;
+                            if w[nm].abs() <= eps * anorm {
+                                break;
+                            }
+                            l -= 1;
+                        }
+
+                        if flag {
+                            // Cancellation of rv1[l] if l > 0
+                            let mut c = 0.0;
+                            let mut s = 1.0;
+                            for i in l..=k {
+                                let f = s * rv1[i];
+                                rv1[i] *= c;
+                                if f.abs() <= eps * anorm {
+                                    break;
+                                }
+                                let g = w[i];
+                                let h = f.hypot(g);
+                                w[i] = h;
+                                c = g / h;
+                                s = -f / h;
+                                for j in 0..m {
+                                    let y = u[(j, nm)];
+                                    let z = u[(j, i)];
+                                    u[(j, nm)] = y * c + z * s;
+                                    u[(j, i)] = z * c - y * s;
+                                }
+                            }
+                        }
+
+                        let z = w[k];
+                        if l == k {
+                            // Convergence, make the singular value non-negative
+                            if z < 0.0 {
+                                w[k] = -z;
+                                for j in 0..n {
+                                    v[(j, k)] = -v[(j, k)];
+                                }
+                            }
+                            break;
+                        }
+                        if its == MAX_ITERATIONS - 1 {
+                            return Err(NoConvergenceError);
+                        }
+
+                        // Shift from the bottom 2 x 2 minor
+                        let mut x = w[l];
+                        nm = k - 1;
+                        let mut y = w[nm];
+                        let mut g = rv1[nm];
+                        let mut h = rv1[k];
+                        let mut f = ((y - z) * (y + z) + (g - h) * (g + h)) / (2.0 * h * y);
+                        g = f.hypot(1.0);
+                        f = ((x - z) * (x + z) + h * ((y / (f + sign(g, f))) - h)) / x;
+
+                        // Next QR transformation
+                        let mut c = 1.0;
+                        let mut s = 1.0;
+                        for j in l..=nm {
+                            let i = j + 1;
+                            g = rv1[i];
+                            y = w[i];
+                            h = s * g;
+                            g *= c;
+                            let mut z = f.hypot(h);
+                            rv1[j] = z;
+                            c = f / z;
+                            s = h / z;
+                            f = x * c + g * s;
+                            g = g * c - x * s;
+                            h = y * s;
+                            y *= c;
+                            for jj in 0..n {
+                                let x = v[(jj, j)];
+                                let z = v[(jj, i)];
+                                v[(jj, j)] = x * c + z * s;
+                                v[(jj, i)] = z * c - x * s;
+                            }
+                            z = f.hypot(h);
+                            w[j] = z;
+                            // The rotation can be arbitrary if z = 0
+                            if z != 0.0 {
+                                c = f / z;
+                                s = h / z;
+                            }
+                            f = c * g + s * y;
+                            x = c * y - s * g;
+                            for jj in 0..m {
+                                let y = u[(jj, j)];
+                                let z = u[(jj, i)];
+                                u[(jj, j)] = y * c + z * s;
+                                u[(jj, i)] = z * c - y * s;
+                            }
+                        }
+                        rv1[l] = 0.0;
+                        rv1[k] = f;
+                        w[k] = x;
+                    }
+                }
+
+                Ok(())
+            }
+
+            /// Sorts the singular values in decreasing order and fixes the sign of each pair of
+            /// singular vectors so that most of their entries are non-negative.
+            fn reorder(u: &mut Matrix<$type>, w: &mut Vector<$type>, v: &mut Matrix<$type>) {
+                let (m, n) = (u.n_rows, u.n_cols);
+
+                let mut order: Vec<usize> = (0..n).collect();
+                order.sort_by(|&a, &b| w[b].partial_cmp(&w[a]).unwrap_or(Ordering::Equal));
+
+                let sorted_w = Vector {
+                    n,
+                    data: order.iter().map(|&j| w[j]).collect(),
+                };
+                let mut sorted_u = Matrix::<$type>::zeros(m, n);
+                let mut sorted_v = Matrix::<$type>::zeros(n, n);
+                for (k, &j) in order.iter().enumerate() {
+                    let negatives = (0..m).filter(|&i| u[(i, j)] < 0.0).count()
+                        + (0..n).filter(|&i| v[(i, j)] < 0.0).count();
+                    let flip = if negatives > (m + n) / 2 { -1.0 } else { 1.0 };
+
+                    for i in 0..m {
+                        sorted_u[(i, k)] = flip * u[(i, j)];
+                    }
+                    for i in 0..n {
+                        sorted_v[(i, k)] = flip * v[(i, j)];
+                    }
+                }
+
+                *u = sorted_u;
+                *w = sorted_w;
+                *v = sorted_v;
+            }
+
+            /// Extends the orthonormal columns of `q` to an orthonormal basis of the whole space.
+            fn complete_basis(q: &Matrix<$type>) -> Matrix<$type> {
+                let (m, k) = (q.n_rows, q.n_cols);
+                let q_full = q.qr().q_full();
+
+                let mut basis = Matrix::<$type>::zeros(m, m);
+                for i in 0..m {
+                    for j in 0..m {
+                        basis[(i, j)] = if j < k { q[(i, j)] } else { q_full[(i, j)] };
+                    }
+                }
+
+                basis
+            }
+
+            /// Default threshold below which singular values are treated as zero.
+            pub fn default_threshold(&self) -> $type {
+                let (m, n) = (self.u.n_rows, self.vt.n_cols);
+                if self.s.n == 0 {
+                    return 0.0;
+                }
+
+                0.5 * ((m + n + 1) as $type).sqrt() * self.s[0] * <$type>::EPSILON
+            }
+
+            /// Number of singular values above `threshold`, or above the default threshold if
+            /// `None`.
+            pub fn rank(&self, threshold: Option<$type>) -> usize {
+                let threshold = threshold.unwrap_or_else(|| self.default_threshold());
+                self.s.data.iter().filter(|&&s| s > threshold).count()
+            }
+
+            /// Ratio of the largest to the smallest singular value, infinite for singular
+            /// matrices.
+            pub fn cond(&self) -> $type {
+                if self.s.n == 0 {
+                    return 0.0;
+                }
+
+                let s_min = self.s[self.s.n - 1];
+                if s_min <= 0.0 {
+                    <$type>::INFINITY
+                } else {
+                    self.s[0] / s_min
+                }
+            }
+
+            /// Orthonormal basis of the range of `A`, one vector per column.
+            pub fn range(&self, threshold: Option<$type>) -> Matrix<$type> {
+                let rank = self.rank(threshold);
+
+                let mut basis = Matrix::<$type>::zeros(self.u.n_rows, rank);
+                for i in 0..self.u.n_rows {
+                    for j in 0..rank {
+                        basis[(i, j)] = self.u[(i, j)];
+                    }
+                }
+
+                basis
+            }
+
+            /// Orthonormal basis of the nullspace of `A`, one vector per column.
+            pub fn nullspace(&self, threshold: Option<$type>) -> Matrix<$type> {
+                let rank = self.rank(threshold);
+                let n = self.vt.n_cols;
+
+                // In thin mode V^T may lack the rows spanning the trailing part of the nullspace
+                let v = if self.vt.n_rows < n {
+                    Self::complete_basis(&self.vt.transpose())
+                } else {
+                    self.vt.transpose()
+                };
+
+                let mut basis = Matrix::<$type>::zeros(n, n - rank);
+                for i in 0..n {
+                    for j in rank..n {
+                        basis[(i, j - rank)] = v[(i, j)];
+                    }
+                }
+
+                basis
+            }
+        }
+    };
+}
+
+impl_svd!(f32);
+impl_svd!(f64);
+
+impl<T> SVD<T> for MatrixView<'_, T>
+where
+    T: Copy,
+    Matrix<T>: SVD<T>,
+{
+    fn svd(&self, mode: SVDMode) -> Result<SVDecomposition<T>, SVDecompositionError> {
+        self.to_matrix().svd(mode)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::core::gemm::gemm;
+
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn assert_matrix_eq(a: &Matrix<f64>, b: &Matrix<f64>) {
+        assert_eq!(a.n_rows, b.n_rows);
+        assert_eq!(a.n_cols, b.n_cols);
+        for i in 0..a.n_rows {
+            for j in 0..a.n_cols {
+                assert_relative_eq!(a[(i, j)], b[(i, j)], epsilon = 1e-10);
+            }
+        }
+    }
+
+    fn reconstruct(svd: &SVDecomposition<f64>) -> Matrix<f64> {
+        let mut s = Matrix::<f64>::zeros(svd.u.n_cols, svd.vt.n_rows);
+        for i in 0..svd.s.n {
+            s[(i, i)] = svd.s[i];
+        }
+
+        gemm(&gemm(&svd.u, &s), &svd.vt)
+    }
+
+    fn assert_orthonormal_cols(q: &Matrix<f64>) {
+        assert_matrix_eq(&gemm(&q.transpose(), q), &Matrix::<f64>::eye(q.n_cols));
+    }
+
+    #[test]
+    fn test_svd_thin() {
+        let m: Matrix<f64> = matrix![3.0, 2.0, 2.0; 2.0, 3.0, -2.0; 1.0, 0.0, 4.0; 0.0, 1.0, 1.0];
+
+        let svd = m.svd(SVDMode::Thin).unwrap();
+        assert_eq!((svd.u().n_rows, svd.u().n_cols), (4, 3));
+        assert_eq!(svd.s().n, 3);
+        assert_eq!((svd.vt().n_rows, svd.vt().n_cols), (3, 3));
+        for i in 1..3 {
+            assert!(svd.s()[i - 1] >= svd.s()[i]);
+        }
+
+        assert_orthonormal_cols(svd.u());
+        assert_orthonormal_cols(&svd.vt().transpose());
+        assert_matrix_eq(&reconstruct(&svd), &m);
+    }
+
+    #[test]
+    fn test_svd_known_values() {
+        let m: Matrix<f64> = matrix![3.0, 2.0, 2.0; 2.0, 3.0, -2.0];
+
+        let svd = m.svd(SVDMode::Thin).unwrap();
+        assert_relative_eq!(svd.s()[0], 5.0, epsilon = 1e-12);
+        assert_relative_eq!(svd.s()[1], 3.0, epsilon = 1e-12);
+        assert_matrix_eq(&reconstruct(&svd), &m);
+    }
+
+    #[test]
+    fn test_svd_full() {
+        let tall: Matrix<f64> = matrix![1.0, 2.0; 3.0, 4.0; 5.0, 6.0];
+        let svd = tall.svd(SVDMode::Full).unwrap();
+        assert_eq!((svd.u().n_rows, svd.u().n_cols), (3, 3));
+        assert_eq!((svd.vt().n_rows, svd.vt().n_cols), (2, 2));
+        assert_orthonormal_cols(svd.u());
+        assert_matrix_eq(&reconstruct(&svd), &tall);
+
+        let wide = tall.transpose();
+        let svd = wide.svd(SVDMode::Full).unwrap();
+        assert_eq!((svd.u().n_rows, svd.u().n_cols), (2, 2));
+        assert_eq!((svd.vt().n_rows, svd.vt().n_cols), (3, 3));
+        assert_orthonormal_cols(&svd.vt().transpose());
+        assert_matrix_eq(&reconstruct(&svd), &wide);
+    }
+
+    #[test]
+    fn test_svd_rank_deficient() {
+        let m: Matrix<f64> = matrix![1.0, 2.0, 3.0; 2.0, 4.0, 6.0; 1.0, 0.0, 1.0];
+
+        let svd = m.svd(SVDMode::Thin).unwrap();
+        assert_eq!(svd.rank(None), 2);
+        assert_eq!(svd.rank(Some(10.0)), 0);
+        assert!(svd.cond() > 1e12);
+
+        let range = svd.range(None);
+        assert_eq!((range.n_rows, range.n_cols), (3, 2));
+
+        let null = svd.nullspace(None);
+        assert_eq!((null.n_rows, null.n_cols), (3, 1));
+        let an = gemm(&m, &null);
+        for i in 0..3 {
+            assert_relative_eq!(an[(i, 0)], 0.0, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_svd_nullspace_wide() {
+        let m: Matrix<f64> = matrix![1.0, 0.0, 1.0, 0.0; 0.0, 1.0, 0.0, 1.0];
+
+        let svd = m.svd(SVDMode::Thin).unwrap();
+        let null = svd.nullspace(None);
+        assert_eq!((null.n_rows, null.n_cols), (4, 2));
+        assert_orthonormal_cols(&null);
+        assert_matrix_eq(&gemm(&m, &null), &Matrix::<f64>::zeros(2, 2));
+    }
+
+    #[test]
+    fn test_svd_cond() {
+        let m: Matrix<f32> = matrix![4.0, 0.0; 0.0, -0.5];
+
+        let svd = m.svd(SVDMode::Thin).unwrap();
+        assert_relative_eq!(svd.s()[0], 4.0);
+        assert_relative_eq!(svd.s()[1], 0.5);
+        assert_relative_eq!(svd.cond(), 8.0);
+    }
+}
@@ -0,0 +1,49 @@
+//! Commonly used types and traits, for glob import with `use numerics::prelude::*`.
+
+pub use crate::core::complex::Complex;
+pub use crate::core::double_double::DoubleDouble;
+pub use crate::core::gemm::{
+    axpy, dot, dotc, gemm, gemm_into, gemv, gemv_into, try_dot, try_gemm, try_gemv, Op,
+};
+pub use crate::core::matrix::{Layout, Matrix};
+#[cfg(feature = "rational")]
+pub use crate::core::rational::Rational;
+pub use crate::core::scalar::{ComplexField, Field, One, RealField, Scalar, Zero};
+pub use crate::core::vector::{Arange, Vector};
+pub use crate::core::view::{AsView, AsViewMut, MatrixView, MatrixViewMut};
+pub use crate::linalg::chol::{Cholesky, CholeskyFactorization};
+pub use crate::linalg::eigen::{Eigen, EigenOptions, SymmetricEigen};
+pub use crate::linalg::inverse::{Determinant, Inverse};
+pub use crate::linalg::lstsq::{lstsq, LeastSquares, LstsqMethod};
+pub use crate::linalg::lu::{LuFactorization, LU};
+pub use crate::linalg::qr::QR;
+pub use crate::linalg::solve::{Solve, SolveField};
+pub use crate::linalg::svd::{SVDMode, SVD};
+pub use crate::matrix;
+
+#[cfg(test)]
+mod test {
+    use approx::assert_relative_eq;
+
+    use super::*;
+
+    #[test]
+    fn test_prelude() {
+        let m: Matrix<f64> = matrix![4.0, 2.0; 2.0, 3.0];
+        let b = Vector::from_vec(&[2.0, 1.0]);
+
+        let chol = m.chol().unwrap();
+        let lu = m.lu().unwrap();
+        let x = chol.solve(&b);
+        let y = lu.solve(&b);
+        assert_relative_eq!(x[0], 0.5, epsilon = 1e-12);
+        assert_relative_eq!(x[1], 0.0, epsilon = 1e-12);
+        assert_relative_eq!(y[0], x[0], epsilon = 1e-12);
+        assert_relative_eq!(y[1], x[1], epsilon = 1e-12);
+
+        let l = chol.l();
+        let m_rec = gemm(l, &l.transpose());
+        assert_relative_eq!(m_rec[(0, 1)], 2.0, epsilon = 1e-12);
+        assert_relative_eq!(dot(&gemv(&m, &x), &x), 1.0, epsilon = 1e-12);
+    }
+}